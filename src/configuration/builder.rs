@@ -73,6 +73,118 @@ impl ConfigurationBuilder {
     self.insert("commentLine.forceSpaceAfterSlashes", value.into())
   }
 
+  /// The style to use for comments.
+  ///
+  /// Default: `CommentStyleKind::Maintain`
+  pub fn comment_style(&mut self, value: CommentStyleKind) -> &mut Self {
+    self.insert("comment.style", value.to_string().into())
+  }
+
+  /// Whether to surround the text of single line block comments with a single space (ex. `/*a*/` to `/* a */`).
+  ///
+  /// Default: `false`
+  pub fn comment_block_space_inside(&mut self, value: bool) -> &mut Self {
+    self.insert("commentBlock.spaceInside", value.into())
+  }
+
+  /// Whether to keep the lines of multi-line block comments as they are instead of
+  /// re-indenting them relative to the comment's new indentation.
+  ///
+  /// Default: `true`
+  pub fn comment_block_preserve_indent(&mut self, value: bool) -> &mut Self {
+    self.insert("commentBlock.preserveIndent", value.into())
+  }
+
+  /// Whether to re-wrap the text of own line comments and JSDoc style block comments
+  /// (ex. `/** ... */`) so that it stays within the comment line width. Consecutive
+  /// line comments of the same paragraph are joined before being wrapped.
+  ///
+  /// Default: `false`
+  pub fn comment_wrap(&mut self, value: bool) -> &mut Self {
+    self.insert("comment.wrap", value.into())
+  }
+
+  /// The width of a line to wrap comments at when `comment.wrap` is enabled.
+  ///
+  /// Default: The line width.
+  pub fn comment_line_width(&mut self, value: u32) -> &mut Self {
+    self.insert("comment.lineWidth", (value as i32).into())
+  }
+
+  /// Whether to align the trailing line comments of consecutive properties or elements
+  /// to the same column. A blank line starts a new group.
+  ///
+  /// Default: `false`
+  pub fn comment_align_trailing(&mut self, value: bool) -> &mut Self {
+    self.insert("comment.alignTrailing", value.into())
+  }
+
+  /// How to handle comments in JSON files, which don't support them.
+  /// JSONC and JSON5 files always keep their comments.
+  ///
+  /// Default: `CommentsKind::Keep`
+  pub fn comments(&mut self, value: CommentsKind) -> &mut Self {
+    self.insert("comments", value.to_string().into())
+  }
+
+  /// Whether to error on syntax that isn't standard JSON (ex. trailing commas, comments,
+  /// single quoted strings, unquoted property names and hexadecimal numbers) in JSON files
+  /// instead of formatting it. Comments are still removed when `comments` is `remove`.
+  ///
+  /// Default: `false`
+  pub fn strict(&mut self, value: bool) -> &mut Self {
+    self.insert("strict", value.into())
+  }
+
+  /// Whether to allow comments when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_comments(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowComments", value.into())
+  }
+
+  /// Whether to allow words and numbers as object property names (ex. `{ prop: 1 }`) when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_loose_object_property_names(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowLooseObjectPropertyNames", value.into())
+  }
+
+  /// Whether to allow trailing commas in objects and arrays when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_trailing_commas(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowTrailingCommas", value.into())
+  }
+
+  /// Whether to allow missing commas between object properties when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_missing_commas(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowMissingCommas", value.into())
+  }
+
+  /// Whether to allow single quoted strings when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_single_quoted_strings(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowSingleQuotedStrings", value.into())
+  }
+
+  /// Whether to allow hexadecimal numbers (ex. `0xFF`) when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_hexadecimal_numbers(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowHexadecimalNumbers", value.into())
+  }
+
+  /// Whether to allow a unary plus sign on numbers (ex. `+1`) when parsing.
+  ///
+  /// Default: `true`
+  pub fn parse_allow_unary_plus_numbers(&mut self, value: bool) -> &mut Self {
+    self.insert("parse.allowUnaryPlusNumbers", value.into())
+  }
+
   /// The text to use for an ignore comment (ex. `// dprint-ignore`).
   ///
   /// Default: `"dprint-ignore"`
@@ -80,6 +192,17 @@ impl ConfigurationBuilder {
     self.insert("ignoreNodeCommentText", value.into())
   }
 
+  /// The texts to use for an ignore comment when honoring the ignore comments of
+  /// multiple formatters (ex. `vec!["dprint-ignore".to_string(), "prettier-ignore".to_string()]`).
+  ///
+  /// Default: `vec!["dprint-ignore".to_string()]`
+  pub fn ignore_node_comment_texts(&mut self, value: Vec<String>) -> &mut Self {
+    self.insert(
+      "ignoreNodeCommentText",
+      ConfigKeyValue::Array(value.into_iter().map(|v| v.into()).collect()),
+    )
+  }
+
   /// Whether to make objects and arrays collapse to a single line when below the line width.
   /// Default: false
   pub fn prefer_single_line(&mut self, value: bool) -> &mut Self {
@@ -92,12 +215,107 @@ impl ConfigurationBuilder {
     self.insert("array.preferSingleLine", value.into())
   }
 
+  /// Whether to put as many elements on each line as fit within the line width when
+  /// an array only contains primitive values (ex. numbers or short strings) and doesn't
+  /// fit on a single line.
+  ///
+  /// Default: `false`
+  pub fn array_fill(&mut self, value: bool) -> &mut Self {
+    self.insert("array.fill", value.into())
+  }
+
+  /// Whether to print each row of an array of same-length number arrays (ex. a matrix
+  /// or lookup table) on its own line with the columns right-aligned. Matrices that fit
+  /// on a single line or have rows that don't fit within the line width aren't aligned.
+  ///
+  /// Default: `false`
+  pub fn array_align_matrix(&mut self, value: bool) -> &mut Self {
+    self.insert("array.alignMatrix", value.into())
+  }
+
+  /// Whether to print each object of an array of flat objects with the same keys
+  /// on its own line with the property values aligned in columns. Arrays that fit
+  /// on a single line or have rows that don't fit within the line width aren't aligned.
+  ///
+  /// Default: `false`
+  pub fn array_table_layout(&mut self, value: bool) -> &mut Self {
+    self.insert("array.tableLayout", value.into())
+  }
+
+  /// Whether to surround the elements of single line arrays with a space (ex. `[ 1, 2 ]`).
+  ///
+  /// Default: `false`
+  pub fn array_space_inside(&mut self, value: bool) -> &mut Self {
+    self.insert("array.spaceInside", value.into())
+  }
+
   /// Whether to make ojects collapse to a single line when below the line width.
   /// Default: false
   pub fn object_prefer_single_line(&mut self, value: bool) -> &mut Self {
     self.insert("object.preferSingleLine", value.into())
   }
 
+  /// How to sort the properties of objects. Properties are sorted within groups
+  /// separated by blank lines and comments move with the property they belong to.
+  ///
+  /// Default: `SortKeysKind::Off`
+  pub fn object_sort_keys(&mut self, value: SortKeysKind) -> &mut Self {
+    self.insert("object.sortKeys", value.to_string().into())
+  }
+
+  /// How to handle properties of an object with the same name. Names are compared
+  /// after decoding escapes (ex. `"a"` and `"\u0061"` are the same). Use
+  /// [`find_duplicate_keys`](crate::find_duplicate_keys) to report them as diagnostics.
+  ///
+  /// Default: `DuplicateKeysKind::Allow`
+  pub fn object_duplicate_keys(&mut self, value: DuplicateKeysKind) -> &mut Self {
+    self.insert("object.duplicateKeys", value.to_string().into())
+  }
+
+  /// Whether to pad the property names of multi-line objects so the values start
+  /// at the same column. A blank line starts a new group.
+  ///
+  /// Default: `false`
+  pub fn object_align_values(&mut self, value: bool) -> &mut Self {
+    self.insert("object.alignValues", value.into())
+  }
+
+  /// The width above which a property name is not aligned when `object.alignValues`
+  /// is enabled.
+  ///
+  /// Default: `20`
+  pub fn object_align_values_max_name_width(&mut self, value: u32) -> &mut Self {
+    self.insert("object.alignValuesMaxNameWidth", (value as i32).into())
+  }
+
+  /// Whether to surround the properties of single line objects with a space (ex. `{ "a": 1 }`).
+  ///
+  /// Default: `true`
+  pub fn object_space_inside(&mut self, value: bool) -> &mut Self {
+    self.insert("object.spaceInside", value.into())
+  }
+
+  /// Whether to put a space before the colon of a property (ex. `"a" : 1`).
+  ///
+  /// Default: `false`
+  pub fn colon_space_before(&mut self, value: bool) -> &mut Self {
+    self.insert("colon.spaceBefore", value.into())
+  }
+
+  /// Whether to put a space after the colon of a property (ex. `"a": 1`).
+  ///
+  /// Default: `true`
+  pub fn colon_space_after(&mut self, value: bool) -> &mut Self {
+    self.insert("colon.spaceAfter", value.into())
+  }
+
+  /// Whether to put a space between the brackets or braces of an empty array or object (ex. `{ }`).
+  ///
+  /// Default: `false`
+  pub fn empty_container_space_inside(&mut self, value: bool) -> &mut Self {
+    self.insert("emptyContainer.spaceInside", value.into())
+  }
+
   /// Whether to use trailing commas.
   ///
   /// Default: `TrailingCommaKind::Jsonc`
@@ -105,7 +323,102 @@ impl ConfigurationBuilder {
     self.insert("trailingCommas", value.to_string().into())
   }
 
+  /// Whether to quote object property names in JSONC and JSON5 files. Property names
+  /// are always quoted in JSON files, including the `jsonTrailingCommaFiles`.
+  ///
+  /// Default: `QuotePropsKind::Always` (`QuotePropsKind::Preserve` in JSON5 files)
+  pub fn quote_props(&mut self, value: QuotePropsKind) -> &mut Self {
+    self.insert("quoteProps", value.to_string().into())
+  }
+
+  /// The quote character to use for strings in JSONC and JSON5 files. Strings are
+  /// always double quoted in JSON files, including the `jsonTrailingCommaFiles`.
+  ///
+  /// Default: `QuoteStyleKind::AlwaysDouble` (`QuoteStyleKind::Preserve` in JSON5 files)
+  pub fn quote_style(&mut self, value: QuoteStyleKind) -> &mut Self {
+    self.insert("quoteStyle", value.to_string().into())
+  }
+
+  /// Whether to remove escapes that aren't necessary (ex. `\/` to `/`).
+  ///
+  /// Default: `false`
+  pub fn string_escapes_remove_unnecessary(&mut self, value: bool) -> &mut Self {
+    self.insert("string.escapes.removeUnnecessary", value.into())
+  }
+
+  /// Whether to replace unicode escapes of printable characters with the character (ex. `\u00e9` to `é`).
+  ///
+  /// Default: `false`
+  pub fn string_escapes_unicode_to_character(&mut self, value: bool) -> &mut Self {
+    self.insert("string.escapes.unicodeToCharacter", value.into())
+  }
+
+  /// The casing to use for the hex digits of unicode escapes.
+  ///
+  /// Default: `HexCaseKind::Maintain`
+  pub fn string_escapes_hex_case(&mut self, value: HexCaseKind) -> &mut Self {
+    self.insert("string.escapes.hexCase", value.to_string().into())
+  }
+
+  /// Whether to use short escapes instead of unicode escapes when possible (ex. `\u000a` to `\n`).
+  ///
+  /// Default: `false`
+  pub fn string_escapes_prefer_short(&mut self, value: bool) -> &mut Self {
+    self.insert("string.escapes.preferShort", value.into())
+  }
+
+  /// Whether to escape all non-ASCII characters in strings and property names (ex. `é` to `\u00e9`).
+  ///
+  /// Default: `false`
+  pub fn ensure_ascii(&mut self, value: bool) -> &mut Self {
+    self.insert("ensureAscii", value.into())
+  }
+
+  /// Whether to escape `<`, `>`, `&`, U+2028 and U+2029 in strings so the
+  /// JSON can be embedded in an HTML `<script>` tag (ex. `<` to `\u003c`).
+  ///
+  /// Default: `false`
+  pub fn html_safe(&mut self, value: bool) -> &mut Self {
+    self.insert("htmlSafe", value.into())
+  }
+
+  /// The casing to use for the exponent marker of numbers.
+  ///
+  /// Default: `ExponentCaseKind::Maintain`
+  pub fn number_exponent_case(&mut self, value: ExponentCaseKind) -> &mut Self {
+    self.insert("number.exponentCase", value.to_string().into())
+  }
+
+  /// Whether to remove the redundant plus sign in exponents (ex. `1e+5` to `1e5`).
+  ///
+  /// Default: `false`
+  pub fn number_remove_exponent_plus_sign(&mut self, value: bool) -> &mut Self {
+    self.insert("number.removeExponentPlusSign", value.into())
+  }
+
+  /// Whether to remove trailing zeros in fractions (ex. `1.50` to `1.5` and `1.0` to `1`).
+  ///
+  /// Default: `false`
+  pub fn number_remove_trailing_fraction_zeros(&mut self, value: bool) -> &mut Self {
+    self.insert("number.removeTrailingFractionZeros", value.into())
+  }
+
+  /// Whether to remove leading zeros in exponents (ex. `1e05` to `1e5`).
+  ///
+  /// Default: `false`
+  pub fn number_remove_exponent_leading_zeros(&mut self, value: bool) -> &mut Self {
+    self.insert("number.removeExponentLeadingZeros", value.into())
+  }
+
+  /// Whether to format negative zero as zero (ex. `-0.0` to `0.0`).
+  ///
+  /// Default: `false`
+  pub fn number_normalize_negative_zero(&mut self, value: bool) -> &mut Self {
+    self.insert("number.normalizeNegativeZero", value.into())
+  }
+
   /// When `trailingCommas` is `jsonc`, treat these files as JSONC and use trailing commas.
+  /// JSONC and JSON5 files are always treated this way.
   ///
   /// Ex. `vec!["tsconfig.json".to_string(), ".vscode/settings.json".to_string()]`
   pub fn json_trailing_comma_files(&mut self, value: Vec<String>) -> &mut Self {
@@ -136,6 +449,8 @@ impl ConfigurationBuilder {
 }
 
 #[cfg(test)]
+// clippy 1.95 flags the existing `assert_eq!` comparisons with literal bools
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
   use dprint_core::configuration::NewLineKind;
   use dprint_core::configuration::resolve_global_config;
@@ -152,15 +467,55 @@ mod tests {
       .indent_width(4)
       .new_line_kind(NewLineKind::CarriageReturnLineFeed)
       .comment_line_force_space_after_slashes(false)
+      .comment_style(CommentStyleKind::Line)
+      .comment_block_space_inside(true)
+      .comment_block_preserve_indent(true)
+      .comment_wrap(true)
+      .comment_line_width(100)
+      .comment_align_trailing(true)
+      .comments(CommentsKind::Remove)
+      .strict(true)
+      .parse_allow_comments(false)
+      .parse_allow_loose_object_property_names(false)
+      .parse_allow_trailing_commas(false)
+      .parse_allow_missing_commas(false)
+      .parse_allow_single_quoted_strings(false)
+      .parse_allow_hexadecimal_numbers(false)
+      .parse_allow_unary_plus_numbers(false)
       .prefer_single_line(true)
       .array_prefer_single_line(true)
+      .array_fill(true)
+      .array_align_matrix(true)
+      .array_table_layout(true)
+      .array_space_inside(true)
       .object_prefer_single_line(false)
+      .object_sort_keys(SortKeysKind::Natural)
+      .object_duplicate_keys(DuplicateKeysKind::KeepLast)
+      .object_align_values(true)
+      .object_align_values_max_name_width(30)
+      .object_space_inside(false)
+      .colon_space_before(true)
+      .colon_space_after(false)
+      .empty_container_space_inside(true)
       .trailing_commas(TrailingCommaKind::Always)
+      .quote_props(QuotePropsKind::AsNeeded)
+      .quote_style(QuoteStyleKind::PreferDouble)
+      .string_escapes_remove_unnecessary(true)
+      .string_escapes_unicode_to_character(true)
+      .string_escapes_hex_case(HexCaseKind::Uppercase)
+      .string_escapes_prefer_short(true)
+      .ensure_ascii(true)
+      .html_safe(true)
+      .number_exponent_case(ExponentCaseKind::Lowercase)
+      .number_remove_exponent_plus_sign(true)
+      .number_remove_trailing_fraction_zeros(true)
+      .number_remove_exponent_leading_zeros(true)
+      .number_normalize_negative_zero(true)
       .json_trailing_comma_files(vec!["tsconfig.json".to_string(), ".vscode/settings.json".to_string()])
       .ignore_node_comment_text("deno-fmt-ignore");
 
     let inner_config = config.get_inner_config();
-    assert_eq!(inner_config.len(), 11);
+    assert_eq!(inner_config.len(), 51);
     let diagnostics = resolve_config(inner_config, &GlobalConfiguration::default()).diagnostics;
     assert_eq!(diagnostics.len(), 0);
   }
@@ -196,7 +551,7 @@ mod tests {
     assert_eq!(config.new_line_kind == NewLineKind::LineFeed, true);
     assert_eq!(config.use_tabs, false);
     assert_eq!(config.comment_line_force_space_after_slashes, false);
-    assert_eq!(config.ignore_node_comment_text, "deno-fmt-ignore");
+    assert_eq!(config.ignore_node_comment_text, vec!["deno-fmt-ignore".to_string()]);
     assert_eq!(config.array_prefer_single_line, false);
     assert_eq!(config.object_prefer_single_line, false);
   }
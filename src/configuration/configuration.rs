@@ -1,7 +1,7 @@
 use dprint_core::configuration::NewLineKind;
 use serde::{Deserialize, Serialize};
 
-use super::types::TrailingCommaKind;
+use super::types::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,11 +12,87 @@ pub struct Configuration {
   pub new_line_kind: NewLineKind,
   #[serde(rename = "commentLine.forceSpaceAfterSlashes")]
   pub comment_line_force_space_after_slashes: bool,
-  pub ignore_node_comment_text: String,
+  #[serde(rename = "comment.style")]
+  pub comment_style: CommentStyleKind,
+  #[serde(rename = "commentBlock.spaceInside")]
+  pub comment_block_space_inside: bool,
+  #[serde(rename = "commentBlock.preserveIndent")]
+  pub comment_block_preserve_indent: bool,
+  #[serde(rename = "comment.wrap")]
+  pub comment_wrap: bool,
+  #[serde(rename = "comment.lineWidth")]
+  pub comment_line_width: Option<u32>,
+  #[serde(rename = "comment.alignTrailing")]
+  pub comment_align_trailing: bool,
+  pub comments: CommentsKind,
+  pub strict: bool,
+  #[serde(rename = "parse.allowComments")]
+  pub parse_allow_comments: bool,
+  #[serde(rename = "parse.allowLooseObjectPropertyNames")]
+  pub parse_allow_loose_object_property_names: bool,
+  #[serde(rename = "parse.allowTrailingCommas")]
+  pub parse_allow_trailing_commas: bool,
+  #[serde(rename = "parse.allowMissingCommas")]
+  pub parse_allow_missing_commas: bool,
+  #[serde(rename = "parse.allowSingleQuotedStrings")]
+  pub parse_allow_single_quoted_strings: bool,
+  #[serde(rename = "parse.allowHexadecimalNumbers")]
+  pub parse_allow_hexadecimal_numbers: bool,
+  #[serde(rename = "parse.allowUnaryPlusNumbers")]
+  pub parse_allow_unary_plus_numbers: bool,
+  pub ignore_node_comment_text: Vec<String>,
   #[serde(rename = "array.preferSingleLine")]
   pub array_prefer_single_line: bool,
+  #[serde(rename = "array.fill")]
+  pub array_fill: bool,
+  #[serde(rename = "array.alignMatrix")]
+  pub array_align_matrix: bool,
+  #[serde(rename = "array.tableLayout")]
+  pub array_table_layout: bool,
+  #[serde(rename = "array.spaceInside")]
+  pub array_space_inside: bool,
   #[serde(rename = "object.preferSingleLine")]
   pub object_prefer_single_line: bool,
+  #[serde(rename = "object.sortKeys")]
+  pub object_sort_keys: SortKeysKind,
+  #[serde(rename = "object.duplicateKeys")]
+  pub object_duplicate_keys: DuplicateKeysKind,
+  #[serde(rename = "object.alignValues")]
+  pub object_align_values: bool,
+  #[serde(rename = "object.alignValuesMaxNameWidth")]
+  pub object_align_values_max_name_width: u32,
+  #[serde(rename = "object.spaceInside")]
+  pub object_space_inside: bool,
+  #[serde(rename = "colon.spaceBefore")]
+  pub colon_space_before: bool,
+  #[serde(rename = "colon.spaceAfter")]
+  pub colon_space_after: bool,
+  #[serde(rename = "emptyContainer.spaceInside")]
+  pub empty_container_space_inside: bool,
   pub trailing_commas: TrailingCommaKind,
+  /// `None` uses the default for the kind of file being formatted.
+  pub quote_props: Option<QuotePropsKind>,
+  /// `None` uses the default for the kind of file being formatted.
+  pub quote_style: Option<QuoteStyleKind>,
+  #[serde(rename = "string.escapes.removeUnnecessary")]
+  pub string_escapes_remove_unnecessary: bool,
+  #[serde(rename = "string.escapes.unicodeToCharacter")]
+  pub string_escapes_unicode_to_character: bool,
+  #[serde(rename = "string.escapes.hexCase")]
+  pub string_escapes_hex_case: HexCaseKind,
+  #[serde(rename = "string.escapes.preferShort")]
+  pub string_escapes_prefer_short: bool,
+  pub ensure_ascii: bool,
+  pub html_safe: bool,
+  #[serde(rename = "number.exponentCase")]
+  pub number_exponent_case: ExponentCaseKind,
+  #[serde(rename = "number.removeExponentPlusSign")]
+  pub number_remove_exponent_plus_sign: bool,
+  #[serde(rename = "number.removeTrailingFractionZeros")]
+  pub number_remove_trailing_fraction_zeros: bool,
+  #[serde(rename = "number.removeExponentLeadingZeros")]
+  pub number_remove_exponent_leading_zeros: bool,
+  #[serde(rename = "number.normalizeNegativeZero")]
+  pub number_normalize_negative_zero: bool,
   pub json_trailing_comma_files: Vec<String>,
 }
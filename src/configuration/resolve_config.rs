@@ -1,6 +1,6 @@
 use super::Configuration;
 use super::builder::ConfigurationBuilder;
-use super::types::TrailingCommaKind;
+use super::types::*;
 use dprint_core::configuration::*;
 
 /// Resolves configuration from a collection of key value strings.
@@ -75,30 +75,110 @@ pub fn resolve_config(
       true,
       &mut diagnostics,
     ),
-    ignore_node_comment_text: get_value(
+    comment_style: get_value(
       &mut config,
-      "ignoreNodeCommentText",
-      String::from("dprint-ignore"),
+      "comment.style",
+      CommentStyleKind::Maintain,
       &mut diagnostics,
     ),
+    comment_block_space_inside: get_value(&mut config, "commentBlock.spaceInside", false, &mut diagnostics),
+    comment_block_preserve_indent: get_value(&mut config, "commentBlock.preserveIndent", true, &mut diagnostics),
+    comment_wrap: get_value(&mut config, "comment.wrap", false, &mut diagnostics),
+    comment_line_width: get_nullable_value(&mut config, "comment.lineWidth", &mut diagnostics),
+    comment_align_trailing: get_value(&mut config, "comment.alignTrailing", false, &mut diagnostics),
+    comments: get_value(&mut config, "comments", CommentsKind::Keep, &mut diagnostics),
+    strict: get_value(&mut config, "strict", false, &mut diagnostics),
+    parse_allow_comments: get_value(&mut config, "parse.allowComments", true, &mut diagnostics),
+    parse_allow_loose_object_property_names: get_value(
+      &mut config,
+      "parse.allowLooseObjectPropertyNames",
+      true,
+      &mut diagnostics,
+    ),
+    parse_allow_trailing_commas: get_value(&mut config, "parse.allowTrailingCommas", true, &mut diagnostics),
+    parse_allow_missing_commas: get_value(&mut config, "parse.allowMissingCommas", true, &mut diagnostics),
+    parse_allow_single_quoted_strings: get_value(&mut config, "parse.allowSingleQuotedStrings", true, &mut diagnostics),
+    parse_allow_hexadecimal_numbers: get_value(&mut config, "parse.allowHexadecimalNumbers", true, &mut diagnostics),
+    parse_allow_unary_plus_numbers: get_value(&mut config, "parse.allowUnaryPlusNumbers", true, &mut diagnostics),
+    ignore_node_comment_text: get_ignore_node_comment_text(&mut config, "ignoreNodeCommentText", &mut diagnostics),
     array_prefer_single_line: get_value(
       &mut config,
       "array.preferSingleLine",
       prefer_single_line,
       &mut diagnostics,
     ),
+    array_fill: get_value(&mut config, "array.fill", false, &mut diagnostics),
+    array_align_matrix: get_value(&mut config, "array.alignMatrix", false, &mut diagnostics),
+    array_table_layout: get_value(&mut config, "array.tableLayout", false, &mut diagnostics),
+    array_space_inside: get_value(&mut config, "array.spaceInside", false, &mut diagnostics),
     object_prefer_single_line: get_value(
       &mut config,
       "object.preferSingleLine",
       prefer_single_line,
       &mut diagnostics,
     ),
+    object_sort_keys: get_value(&mut config, "object.sortKeys", SortKeysKind::Off, &mut diagnostics),
+    object_duplicate_keys: get_value(
+      &mut config,
+      "object.duplicateKeys",
+      DuplicateKeysKind::Allow,
+      &mut diagnostics,
+    ),
+    object_align_values: get_value(&mut config, "object.alignValues", false, &mut diagnostics),
+    object_align_values_max_name_width: get_value(&mut config, "object.alignValuesMaxNameWidth", 20, &mut diagnostics),
+    object_space_inside: get_value(&mut config, "object.spaceInside", true, &mut diagnostics),
+    colon_space_before: get_value(&mut config, "colon.spaceBefore", false, &mut diagnostics),
+    colon_space_after: get_value(&mut config, "colon.spaceAfter", true, &mut diagnostics),
+    empty_container_space_inside: get_value(&mut config, "emptyContainer.spaceInside", false, &mut diagnostics),
     trailing_commas: get_value(
       &mut config,
       "trailingCommas",
       TrailingCommaKind::Maintain,
       &mut diagnostics,
     ),
+    quote_props: get_nullable_value(&mut config, "quoteProps", &mut diagnostics),
+    quote_style: get_nullable_value(&mut config, "quoteStyle", &mut diagnostics),
+    string_escapes_remove_unnecessary: get_value(
+      &mut config,
+      "string.escapes.removeUnnecessary",
+      false,
+      &mut diagnostics,
+    ),
+    string_escapes_unicode_to_character: get_value(
+      &mut config,
+      "string.escapes.unicodeToCharacter",
+      false,
+      &mut diagnostics,
+    ),
+    string_escapes_hex_case: get_value(
+      &mut config,
+      "string.escapes.hexCase",
+      HexCaseKind::Maintain,
+      &mut diagnostics,
+    ),
+    string_escapes_prefer_short: get_value(&mut config, "string.escapes.preferShort", false, &mut diagnostics),
+    ensure_ascii: get_value(&mut config, "ensureAscii", false, &mut diagnostics),
+    html_safe: get_value(&mut config, "htmlSafe", false, &mut diagnostics),
+    number_exponent_case: get_value(
+      &mut config,
+      "number.exponentCase",
+      ExponentCaseKind::Maintain,
+      &mut diagnostics,
+    ),
+    number_remove_exponent_plus_sign: get_value(&mut config, "number.removeExponentPlusSign", false, &mut diagnostics),
+    number_remove_trailing_fraction_zeros: get_value(
+      &mut config,
+      "number.removeTrailingFractionZeros",
+      false,
+      &mut diagnostics,
+    ),
+    number_remove_exponent_leading_zeros: get_value(
+      &mut config,
+      "number.removeExponentLeadingZeros",
+      false,
+      &mut diagnostics,
+    ),
+    number_normalize_negative_zero: get_value(&mut config, "number.normalizeNegativeZero", false, &mut diagnostics),
     json_trailing_comma_files: get_trailing_comma_files(&mut config, "jsonTrailingCommaFiles", &mut diagnostics),
   };
 
@@ -118,6 +198,42 @@ fn fill_deno_config(config: &mut ConfigKeyMap) {
   }
 }
 
+fn get_ignore_node_comment_text(
+  config: &mut ConfigKeyMap,
+  key: &str,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Vec<String> {
+  let values = match config.shift_remove(key) {
+    Some(ConfigKeyValue::String(value)) => vec![ConfigKeyValue::String(value)],
+    Some(ConfigKeyValue::Array(values)) => values,
+    Some(_) => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: key.to_string(),
+        message: "Expected a string or an array of strings.".to_string(),
+      });
+      return vec![String::from("dprint-ignore")];
+    }
+    None => return vec![String::from("dprint-ignore")],
+  };
+
+  let mut entries = Vec::with_capacity(values.len());
+  for (i, value) in values.into_iter().enumerate() {
+    match value {
+      // an empty text would match every comment
+      ConfigKeyValue::String(value) if value.is_empty() => diagnostics.push(ConfigurationDiagnostic {
+        property_name: key.to_string(),
+        message: format!("Expected element at index {} to not be empty.", i),
+      }),
+      ConfigKeyValue::String(value) => entries.push(value),
+      _ => diagnostics.push(ConfigurationDiagnostic {
+        property_name: key.to_string(),
+        message: format!("Expected element at index {} to be a string.", i),
+      }),
+    }
+  }
+  entries
+}
+
 fn get_trailing_comma_files(
   config: &mut ConfigKeyMap,
   key: &str,
@@ -174,6 +290,89 @@ mod test {
 
   use super::resolve_config;
 
+  #[test]
+  fn ignore_node_comment_text() {
+    let global_config = GlobalConfiguration::default();
+    {
+      let result = resolve_config(ConfigKeyMap::new(), &global_config);
+      assert!(result.diagnostics.is_empty());
+      assert_eq!(
+        result.config.ignore_node_comment_text,
+        vec!["dprint-ignore".to_string()]
+      );
+    }
+    {
+      let result = resolve_config(
+        ConfigKeyMap::from([(
+          "ignoreNodeCommentText".to_string(),
+          ConfigKeyValue::String("prettier-ignore".to_string()),
+        )]),
+        &global_config,
+      );
+      assert!(result.diagnostics.is_empty());
+      assert_eq!(
+        result.config.ignore_node_comment_text,
+        vec!["prettier-ignore".to_string()]
+      );
+    }
+    {
+      let result = resolve_config(
+        ConfigKeyMap::from([(
+          "ignoreNodeCommentText".to_string(),
+          ConfigKeyValue::Array(vec![
+            ConfigKeyValue::String("prettier-ignore".to_string()),
+            ConfigKeyValue::String("".to_string()),
+            ConfigKeyValue::Bool(true),
+            ConfigKeyValue::String("biome-ignore".to_string()),
+          ]),
+        )]),
+        &global_config,
+      );
+      assert_eq!(result.diagnostics.len(), 2);
+      assert_eq!(
+        result.diagnostics[0].message,
+        "Expected element at index 1 to not be empty."
+      );
+      assert_eq!(
+        result.diagnostics[1].message,
+        "Expected element at index 2 to be a string."
+      );
+      assert_eq!(
+        result.config.ignore_node_comment_text,
+        vec!["prettier-ignore".to_string(), "biome-ignore".to_string()]
+      );
+    }
+  }
+
+  #[test]
+  fn parse_options() {
+    let global_config = GlobalConfiguration::default();
+    let result = resolve_config(ConfigKeyMap::new(), &global_config);
+    assert!(result.config.parse_allow_comments);
+    assert!(result.config.parse_allow_trailing_commas);
+
+    let result = resolve_config(
+      ConfigKeyMap::from([
+        ("parse.allowComments".to_string(), ConfigKeyValue::Bool(false)),
+        (
+          "parse.allowTrailingCommas".to_string(),
+          ConfigKeyValue::String("no".to_string()),
+        ),
+        ("parse.allowNaN".to_string(), ConfigKeyValue::Bool(true)),
+      ]),
+      &global_config,
+    );
+    assert!(!result.config.parse_allow_comments);
+    assert!(result.config.parse_allow_trailing_commas);
+    let mut diagnostics = result
+      .diagnostics
+      .iter()
+      .map(|d| d.property_name.as_str())
+      .collect::<Vec<_>>();
+    diagnostics.sort();
+    assert_eq!(diagnostics, vec!["parse.allowNaN", "parse.allowTrailingCommas"]);
+  }
+
   #[test]
   fn json_trailing_comma_files() {
     let global_config = GlobalConfiguration::default();
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 pub enum TrailingCommaKind {
   /// Always format with trailing commas. Beware: trailing commas can cause many JSON parsers to fail.
   Always,
-  /// Use trailing commas in JSONC files and do not use trailing commas in JSON files. Beware: trailing commas can cause some JSONC parsers to fail.
+  /// Use trailing commas in JSONC and JSON5 files and do not use trailing commas in JSON files. Beware: trailing commas can cause some JSONC parsers to fail.
   Jsonc,
   /// Keep the trailing comma if it exists. (Default)
   Maintain,
@@ -22,3 +22,165 @@ generate_str_to_from![
   [Never, "never"],
   [Jsonc, "jsonc"]
 ];
+
+/// How to sort the properties of an object.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortKeysKind {
+  /// Maintain the order of the properties. (Default)
+  Off,
+  /// Sort the properties by name in ascending order.
+  Ascending,
+  /// Sort the properties by name in descending order.
+  Descending,
+  /// Sort the properties by name in ascending order, comparing runs of digits numerically (ex. `item2` before `item10`).
+  Natural,
+  /// Sort the properties by name in ascending order, ignoring casing.
+  CaseInsensitive,
+}
+
+generate_str_to_from![
+  SortKeysKind,
+  [Off, "off"],
+  [Ascending, "ascending"],
+  [Descending, "descending"],
+  [Natural, "natural"],
+  [CaseInsensitive, "caseInsensitive"]
+];
+
+/// How to handle properties of an object with the same name.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DuplicateKeysKind {
+  /// Keep all the properties. (Default)
+  Allow,
+  /// Error on the first property with the same name as a previous one.
+  Error,
+  /// Remove all but the last property with each name.
+  KeepLast,
+  /// Remove all but the first property with each name.
+  KeepFirst,
+}
+
+generate_str_to_from![
+  DuplicateKeysKind,
+  [Allow, "allow"],
+  [Error, "error"],
+  [KeepLast, "keepLast"],
+  [KeepFirst, "keepFirst"]
+];
+
+/// Whether to quote object property names in JSONC and JSON5 files.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuotePropsKind {
+  /// Always quote property names. (Default)
+  Always,
+  /// Only quote property names when they are not valid identifiers.
+  AsNeeded,
+  /// Quote all the property names of an object when at least one of them is not a valid identifier.
+  Consistent,
+  /// Keep property names quoted or unquoted as they are.
+  Preserve,
+}
+
+generate_str_to_from![
+  QuotePropsKind,
+  [Always, "always"],
+  [AsNeeded, "asNeeded"],
+  [Consistent, "consistent"],
+  [Preserve, "preserve"]
+];
+
+/// The quote character to use for strings in JSONC and JSON5 files.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyleKind {
+  /// Always use double quotes. (Default)
+  AlwaysDouble,
+  /// Always use single quotes.
+  AlwaysSingle,
+  /// Use double quotes unless a string contains more double quotes than single quotes.
+  PreferDouble,
+  /// Keep the quotes as they are.
+  Preserve,
+}
+
+generate_str_to_from![
+  QuoteStyleKind,
+  [AlwaysDouble, "alwaysDouble"],
+  [AlwaysSingle, "alwaysSingle"],
+  [PreferDouble, "preferDouble"],
+  [Preserve, "preserve"]
+];
+
+/// The casing to use for the exponent marker of numbers.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExponentCaseKind {
+  /// Keep the exponent marker as it is. (Default)
+  Maintain,
+  /// Use a lowercase exponent marker (ex. `1e5`).
+  Lowercase,
+  /// Use an uppercase exponent marker (ex. `1E5`).
+  Uppercase,
+}
+
+generate_str_to_from![
+  ExponentCaseKind,
+  [Maintain, "maintain"],
+  [Lowercase, "lowercase"],
+  [Uppercase, "uppercase"]
+];
+
+/// The casing to use for the hex digits of unicode escapes.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HexCaseKind {
+  /// Keep the hex digits as they are. (Default)
+  Maintain,
+  /// Use lowercase hex digits (ex. `\u00e9`).
+  Lowercase,
+  /// Use uppercase hex digits (ex. `\u00E9`).
+  Uppercase,
+}
+
+generate_str_to_from![
+  HexCaseKind,
+  [Maintain, "maintain"],
+  [Lowercase, "lowercase"],
+  [Uppercase, "uppercase"]
+];
+
+/// The style to use for comments.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentStyleKind {
+  /// Keep comments in the style they're written. (Default)
+  Maintain,
+  /// Convert single line block comments that are followed by a newline to line comments (ex. `/* a */` to `// a`).
+  Line,
+  /// Convert line comments to block comments (ex. `// a` to `/* a */`).
+  Block,
+}
+
+generate_str_to_from![
+  CommentStyleKind,
+  [Maintain, "maintain"],
+  [Line, "line"],
+  [Block, "block"]
+];
+
+/// How to handle comments in JSON files, which don't support them.
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentsKind {
+  /// Keep the comments. (Default)
+  Keep,
+  /// Remove the comments along with the lines they occupied.
+  Remove,
+  /// Error on the first comment.
+  Error,
+}
+
+generate_str_to_from![CommentsKind, [Keep, "keep"], [Remove, "remove"], [Error, "error"]];
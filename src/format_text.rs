@@ -1,58 +1,236 @@
+use std::borrow::Cow;
 use std::path::Path;
 
 use dprint_core::configuration::resolve_new_line_kind;
+use dprint_core::formatting::PrintItems;
 use dprint_core::formatting::PrintOptions;
+use dprint_core::formatting::Signal;
 use jsonc_parser::CollectOptions;
 use jsonc_parser::CommentCollectionStrategy;
+use jsonc_parser::ParseOptions;
 use jsonc_parser::ParseResult;
+use jsonc_parser::Scanner;
+use jsonc_parser::common::Range;
+use jsonc_parser::common::Ranged;
 use jsonc_parser::errors::ParseError;
 use jsonc_parser::parse_to_ast;
+use jsonc_parser::tokens::Token;
 
+use super::configuration::CommentsKind;
 use super::configuration::Configuration;
+use super::configuration::DuplicateKeysKind;
+use super::generation::FileKind;
+use super::generation::find_duplicate_keys as find_duplicate_key_names;
 use super::generation::generate;
+use super::generation::generate_node;
+use super::generation::get_range_node;
 
 /// Error that occurs while formatting.
 ///
 /// The [`Display`](std::fmt::Display) output is a formatted diagnostic, while
-/// the underlying [`ParseError`] can be recovered via [`Error::source`](std::error::Error::source).
+/// the underlying [`ParseError`], when the error occurred while parsing, can be
+/// recovered via [`Error::source`](std::error::Error::source).
 #[derive(Debug, thiserror::Error)]
 #[error("{diagnostic}")]
 pub struct FormatError {
   diagnostic: String,
+  message: String,
+  range: Range,
   #[source]
-  source: ParseError,
+  source: Option<ParseError>,
 }
 
 impl FormatError {
+  fn new(range: Range, message: String, text: &str, source: Option<ParseError>) -> Self {
+    let diagnostic =
+      dprint_core::formatting::utils::string_utils::format_diagnostic(Some((range.start, range.end)), &message, text);
+    Self {
+      diagnostic,
+      message,
+      range,
+      source,
+    }
+  }
+
   /// The error message without position or source highlight (ex. `Unexpected token`).
   pub fn message(&self) -> String {
-    self.source.kind().to_string()
+    self.message.clone()
   }
 
-  /// The parser error that caused this formatting error.
-  pub fn parse_error(&self) -> &ParseError {
-    &self.source
+  /// The byte range in the text the error occurred at.
+  pub fn range(&self) -> std::ops::Range<usize> {
+    self.range.start..self.range.end
+  }
+
+  /// The parser error that caused this formatting error, if it occurred while parsing.
+  ///
+  /// This is `None` for errors found after parsing, such as duplicate property names
+  /// when `object.duplicateKeys` is `error`.
+  pub fn parse_error(&self) -> Option<&ParseError> {
+    self.source.as_ref()
   }
 }
 
 pub fn format_text(path: &Path, text: &str, config: &Configuration) -> Result<Option<String>, FormatError> {
+  if has_ignore_file_comment(strip_bom(text), config) {
+    return Ok(None);
+  }
   let result = format_text_inner(path, text, config)?;
   if result == text { Ok(None) } else { Ok(Some(result)) }
 }
 
 fn format_text_inner(path: &Path, text: &str, config: &Configuration) -> Result<String, FormatError> {
   let text = strip_bom(text);
-  let parse_result = parse(text)?;
-  let is_jsonc = is_jsonc_file(path, config);
+  let file_kind = get_file_kind(path);
+  let is_special_json_file = is_special_json_file(path, config);
+  let is_jsonc = file_kind != FileKind::Json || is_special_json_file;
+  let text = remove_comments_if_configured(text, is_jsonc, config);
+  let parse_text = replace_unsupported_json5_syntax(&text, file_kind);
+  let parse_result = parse(&parse_text, &text, &get_parse_options(is_jsonc, config))?;
+  check_duplicate_keys(&parse_result, &text, config)?;
   Ok(dprint_core::formatting::format(
-    || generate(parse_result, text, config, is_jsonc),
-    config_to_print_options(text, config),
+    || generate(parse_result, &text, config, file_kind, is_special_json_file),
+    config_to_print_options(&text, config),
   ))
 }
 
+/// Result of formatting a range of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatRangeResult {
+  /// The byte range in the original text that should be replaced.
+  pub range: std::ops::Range<usize>,
+  /// The formatted text to replace the range with.
+  pub text: String,
+}
+
+/// Formats only the smallest object, array, or object property that contains the provided byte range.
+///
+/// Returns `None` when the range is outside the root object or array or the node is already formatted.
+pub fn format_range(
+  path: &Path,
+  text: &str,
+  range: std::ops::Range<usize>,
+  config: &Configuration,
+) -> Result<Option<FormatRangeResult>, FormatError> {
+  let bom_len = text.len() - strip_bom(text).len();
+  let text = strip_bom(text);
+  if has_ignore_file_comment(text, config) {
+    return Ok(None);
+  }
+  let range = Range::new(range.start.saturating_sub(bom_len), range.end.saturating_sub(bom_len));
+  let file_kind = get_file_kind(path);
+  let is_special_json_file = is_special_json_file(path, config);
+  let is_jsonc = file_kind != FileKind::Json || is_special_json_file;
+  // removing the comments keeps the positions in the text the same
+  let formatting_text = remove_comments_if_configured(text, is_jsonc, config);
+  let parse_text = replace_unsupported_json5_syntax(&formatting_text, file_kind);
+  let parse_result = parse(&parse_text, &formatting_text, &get_parse_options(is_jsonc, config))?;
+  check_duplicate_keys(&parse_result, &formatting_text, config)?;
+  let Some(node_range) = parse_result
+    .value
+    .as_ref()
+    .and_then(|value| get_range_node(value, range))
+    .map(|node| node.range())
+  else {
+    return Ok(None);
+  };
+
+  // the formatted node is placed after the existing text on its first line
+  let line_start = text[..node_range.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+  let line_prefix = &text[line_start..node_range.start];
+  let line_text = line_prefix.trim_start_matches([' ', '\t']);
+  let indent_text = &line_prefix[..line_prefix.len() - line_text.len()];
+  let indent_level = get_indent_level(indent_text, config);
+
+  let formatted_text = dprint_core::formatting::format(
+    || {
+      let mut items = PrintItems::new();
+      for _ in 0..indent_level {
+        items.push_signal(Signal::StartIndent);
+      }
+      if !line_text.is_empty() {
+        items.push_string(line_text.to_string());
+      }
+      items.extend(generate_node(
+        parse_result,
+        &formatting_text,
+        config,
+        file_kind,
+        is_special_json_file,
+        range,
+      ));
+      for _ in 0..indent_level {
+        items.push_signal(Signal::FinishIndent);
+      }
+      items
+    },
+    config_to_print_options(&formatting_text, config),
+  );
+  let printed_indent_len = if config.use_tabs {
+    indent_level
+  } else {
+    indent_level * config.indent_width as usize
+  };
+  let formatted_text = &formatted_text[printed_indent_len + line_text.len()..];
+
+  if formatted_text == &text[node_range.start..node_range.end] {
+    Ok(None)
+  } else {
+    Ok(Some(FormatRangeResult {
+      range: node_range.start + bom_len..node_range.end + bom_len,
+      text: formatted_text.to_string(),
+    }))
+  }
+}
+
+/// A property with the same name as a previous property of its object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+  /// The name of the property after decoding escapes (ex. `a` for `"\u0061"`).
+  pub name: String,
+  /// The byte range of the property name in the text.
+  pub range: std::ops::Range<usize>,
+  /// The formatted diagnostic (ex. `Line 1, column 10: Duplicate property name "a"` followed by the source).
+  pub diagnostic: String,
+}
+
+/// Finds the properties that have the same name as a previous property of their object.
+///
+/// These are reported regardless of `object.duplicateKeys`, so that the properties removed
+/// when it's `keepFirst` or `keepLast` can be surfaced as diagnostics.
+pub fn find_duplicate_keys(path: &Path, text: &str, config: &Configuration) -> Result<Vec<DuplicateKey>, FormatError> {
+  let bom_len = text.len() - strip_bom(text).len();
+  let text = strip_bom(text);
+  let file_kind = get_file_kind(path);
+  let is_jsonc = file_kind != FileKind::Json || is_special_json_file(path, config);
+  let parse_text = replace_unsupported_json5_syntax(text, file_kind);
+  let parse_result = parse(&parse_text, text, &get_parse_options(is_jsonc, config))?;
+  let Some(value) = parse_result.value.as_ref() else {
+    return Ok(Vec::new());
+  };
+  Ok(
+    find_duplicate_key_names(value)
+      .into_iter()
+      .map(|name| {
+        let range = name.range();
+        let message = format!("Duplicate property name {:?}", name.as_str());
+        DuplicateKey {
+          name: name.as_str().to_string(),
+          range: range.start + bom_len..range.end + bom_len,
+          diagnostic: dprint_core::formatting::utils::string_utils::format_diagnostic(
+            Some((range.start, range.end)),
+            &message,
+            text,
+          ),
+        }
+      })
+      .collect(),
+  )
+}
+
 #[cfg(feature = "tracing")]
 pub fn trace_file(text: &str, config: &Configuration) -> dprint_core::formatting::TracingResult {
-  let parse_result = parse(text).unwrap();
+  let parse_result = parse(text, text, &Default::default()).unwrap();
 
   dprint_core::formatting::trace_printing(
     || generate(parse_result, text, config),
@@ -60,30 +238,269 @@ pub fn trace_file(text: &str, config: &Configuration) -> dprint_core::formatting
   )
 }
 
+/// Gets if a comment before the first token has the ignore file text (ex. `// dprint-ignore-file`).
+///
+/// This is checked without parsing so that files which can't be parsed can still opt out of formatting.
+fn has_ignore_file_comment(text: &str, config: &Configuration) -> bool {
+  let ignore_texts = config
+    .ignore_node_comment_text
+    .iter()
+    .map(|text| format!("{}-file", text))
+    .collect::<Vec<_>>();
+  let mut text = text;
+  loop {
+    text = text.trim_start();
+    let comment_text = if let Some(rest) = text.strip_prefix("//") {
+      let end = rest.find('\n').unwrap_or(rest.len());
+      text = &rest[end..];
+      &rest[..end]
+    } else if let Some(rest) = text.strip_prefix("/*") {
+      let Some(end) = rest.find("*/") else {
+        return false;
+      };
+      text = &rest[end + 2..];
+      &rest[..end]
+    } else {
+      return false;
+    };
+    if ignore_texts
+      .iter()
+      .any(|ignore_text| dprint_core::formatting::ir_helpers::text_has_dprint_ignore(comment_text, ignore_text))
+    {
+      return true;
+    }
+  }
+}
+
+/// Replaces the comments of JSON files with spaces when configured to remove them.
+///
+/// The line break of a line that only had comments is replaced as well so the removed
+/// comments don't leave blank lines behind. Positions in the text stay the same.
+fn remove_comments_if_configured<'a>(text: &'a str, is_jsonc: bool, config: &Configuration) -> Cow<'a, str> {
+  if is_jsonc || config.comments != CommentsKind::Remove {
+    return Cow::Borrowed(text);
+  }
+
+  let mut scanner = Scanner::new(text, &Default::default());
+  let mut comment_ranges = Vec::new();
+  // a scan error will be surfaced when parsing
+  while let Ok(Some(token)) = scanner.scan() {
+    if matches!(token, Token::CommentLine(_) | Token::CommentBlock(_)) {
+      comment_ranges.push(scanner.token_start()..scanner.token_end());
+    }
+  }
+  if comment_ranges.is_empty() {
+    return Cow::Borrowed(text);
+  }
+
+  let mut bytes = text.as_bytes().to_vec();
+  for range in &comment_ranges {
+    bytes[range.clone()].fill(b' ');
+  }
+  for range in &comment_ranges {
+    let line_start = bytes[..range.start]
+      .iter()
+      .rposition(|b| *b == b'\n')
+      .map(|i| i + 1)
+      .unwrap_or(0);
+    let Some(line_end) = bytes[range.end..]
+      .iter()
+      .position(|b| *b == b'\n')
+      .map(|i| range.end + i)
+    else {
+      continue;
+    };
+    if bytes[line_start..line_end].iter().all(|b| b.is_ascii_whitespace()) {
+      bytes[line_end] = b' ';
+    }
+  }
+  // whole comments were replaced, so the text is still valid utf-8
+  Cow::Owned(String::from_utf8(bytes).unwrap())
+}
+
+/// Replaces the JSON5 syntax jsonc-parser doesn't support with text of the same length that it does.
+///
+/// This handles `Infinity`, `NaN`, numbers with a leading or trailing decimal point
+/// (ex. `.5` or `5.`) and line continuations in strings. The replaced text is only
+/// used for parsing and the original text is still used when generating.
+fn replace_unsupported_json5_syntax(text: &str, file_kind: FileKind) -> Cow<'_, str> {
+  if file_kind != FileKind::Json5 {
+    return Cow::Borrowed(text);
+  }
+
+  let bytes = text.as_bytes();
+  let mut new_bytes: Option<Vec<u8>> = None;
+  let mut replace = |start: usize, replacement: &[u8]| {
+    new_bytes.get_or_insert_with(|| bytes.to_vec())[start..start + replacement.len()].copy_from_slice(replacement);
+  };
+  let is_word_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || !b.is_ascii();
+  let mut index = 0;
+  while index < bytes.len() {
+    match bytes[index] {
+      b'/' if bytes.get(index + 1) == Some(&b'/') => {
+        index = text[index..].find('\n').map(|i| index + i).unwrap_or(bytes.len());
+      }
+      b'/' if bytes.get(index + 1) == Some(&b'*') => {
+        index = text[index + 2..]
+          .find("*/")
+          .map(|i| index + i + 4)
+          .unwrap_or(bytes.len());
+      }
+      quote @ (b'"' | b'\'') => {
+        index += 1;
+        while index < bytes.len() && bytes[index] != quote {
+          if bytes[index] == b'\\' {
+            let line_terminator_len = get_line_terminator_len(&text[index + 1..]);
+            if line_terminator_len > 0 {
+              // parsed as `\n` followed by other characters
+              replace(index + 1, &b"nnn"[..line_terminator_len]);
+            }
+            index += line_terminator_len.max(1);
+          }
+          index += 1;
+        }
+        index += 1;
+      }
+      b'.' if bytes.get(index + 1).is_some_and(|b| b.is_ascii_digit()) => {
+        replace(index, b"1");
+        index += 1;
+      }
+      b'0'..=b'9' => {
+        let start = index;
+        let is_hex = matches!(bytes.get(index + 1), Some(b'x' | b'X'));
+        let mut dot_index = None;
+        let mut exponent_index = None;
+        while index < bytes.len() {
+          match bytes[index] {
+            b'.' => dot_index = Some(index),
+            b'e' | b'E' if !is_hex => exponent_index = Some(index),
+            b'+' | b'-' if exponent_index == Some(index - 1) => {}
+            b if b.is_ascii_alphanumeric() => {}
+            _ => break,
+          }
+          index += 1;
+        }
+        let Some(dot_index) = dot_index else {
+          continue;
+        };
+        let integer_end = exponent_index.unwrap_or(index);
+        if dot_index + 1 == integer_end && dot_index > start {
+          // move the digits after the decimal point back and fill the end
+          let mut replacement = bytes[dot_index + 1..index].to_vec();
+          replacement.push(if exponent_index.is_some() { b'0' } else { b' ' });
+          replace(dot_index, &replacement);
+        }
+      }
+      b if is_word_byte(b) => {
+        let start = index;
+        while index < bytes.len() && is_word_byte(bytes[index]) {
+          index += 1;
+        }
+        let is_prop_name = text[index..].trim_start().starts_with(':');
+        match &text[start..index] {
+          "Infinity" if !is_prop_name => replace(start, b"99999999"),
+          "NaN" if !is_prop_name => replace(start, b"999"),
+          _ => {}
+        }
+      }
+      _ => index += 1,
+    }
+  }
+
+  match new_bytes {
+    // only ascii text was replaced, so the text is still valid utf-8
+    Some(new_bytes) => Cow::Owned(String::from_utf8(new_bytes).unwrap()),
+    None => Cow::Borrowed(text),
+  }
+}
+
+/// Gets the length in bytes of the line terminator at the start of the text, or zero if there is none.
+fn get_line_terminator_len(text: &str) -> usize {
+  if text.starts_with("\r\n") {
+    2
+  } else if text.starts_with(['\n', '\r']) {
+    1
+  } else if text.starts_with(['\u{2028}', '\u{2029}']) {
+    3
+  } else {
+    0
+  }
+}
+
+fn get_parse_options(is_jsonc: bool, config: &Configuration) -> ParseOptions {
+  let is_json = !is_jsonc;
+  if is_json && config.strict {
+    return ParseOptions {
+      allow_comments: false,
+      allow_loose_object_property_names: false,
+      allow_trailing_commas: false,
+      allow_missing_commas: false,
+      allow_single_quoted_strings: false,
+      allow_hexadecimal_numbers: false,
+      allow_unary_plus_numbers: false,
+    };
+  }
+  ParseOptions {
+    allow_comments: config.parse_allow_comments && (!is_json || config.comments != CommentsKind::Error),
+    allow_loose_object_property_names: config.parse_allow_loose_object_property_names,
+    allow_trailing_commas: config.parse_allow_trailing_commas,
+    allow_missing_commas: config.parse_allow_missing_commas,
+    allow_single_quoted_strings: config.parse_allow_single_quoted_strings,
+    allow_hexadecimal_numbers: config.parse_allow_hexadecimal_numbers,
+    allow_unary_plus_numbers: config.parse_allow_unary_plus_numbers,
+  }
+}
+
 fn strip_bom(text: &str) -> &str {
   text.strip_prefix("\u{FEFF}").unwrap_or(text)
 }
 
-fn parse(text: &str) -> Result<ParseResult<'_>, FormatError> {
+/// Parses the text, which may have had syntax replaced (see `replace_unsupported_json5_syntax`),
+/// while using the original text for the error diagnostic.
+fn parse<'a>(parse_text: &'a str, text: &str, parse_options: &ParseOptions) -> Result<ParseResult<'a>, FormatError> {
   let parse_result = parse_to_ast(
-    text,
+    parse_text,
     &CollectOptions {
       comments: CommentCollectionStrategy::Separate,
       tokens: true,
     },
-    &Default::default(),
+    parse_options,
   );
   match parse_result {
     Ok(result) => Ok(result),
-    Err(err) => {
-      let diagnostic = dprint_core::formatting::utils::string_utils::format_diagnostic(
-        Some((err.range().start, err.range().end)),
-        &err.kind().to_string(),
-        text,
-      );
-      Err(FormatError { diagnostic, source: err })
+    Err(err) => Err(FormatError::new(err.range(), err.kind().to_string(), text, Some(err))),
+  }
+}
+
+fn check_duplicate_keys(parse_result: &ParseResult, text: &str, config: &Configuration) -> Result<(), FormatError> {
+  if config.object_duplicate_keys != DuplicateKeysKind::Error {
+    return Ok(());
+  }
+  match parse_result
+    .value
+    .as_ref()
+    .and_then(|value| find_duplicate_key_names(value).into_iter().next())
+  {
+    Some(name) => Err(FormatError::new(
+      name.range(),
+      format!("Duplicate property name {:?}", name.as_str()),
+      text,
+      None,
+    )),
+    None => Ok(()),
+  }
+}
+
+fn get_indent_level(indent_text: &str, config: &Configuration) -> usize {
+  let indent_width = std::cmp::max(config.indent_width as usize, 1);
+  let mut width = 0;
+  for c in indent_text.chars() {
+    match c {
+      '\t' => width += indent_width - width % indent_width,
+      _ => width += 1,
     }
   }
+  width / indent_width
 }
 
 fn config_to_print_options(text: &str, config: &Configuration) -> PrintOptions {
@@ -95,34 +512,38 @@ fn config_to_print_options(text: &str, config: &Configuration) -> PrintOptions {
   }
 }
 
-fn is_jsonc_file(path: &Path, config: &Configuration) -> bool {
-  fn has_jsonc_extension(path: &Path) -> bool {
-    if let Some(ext) = path.extension() {
-      return ext.to_string_lossy().to_ascii_lowercase() == "jsonc";
-    }
-
-    false
+fn get_file_kind(path: &Path) -> FileKind {
+  match path
+    .extension()
+    .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+    .as_deref()
+  {
+    Some("jsonc") => FileKind::Jsonc,
+    Some("json5") => FileKind::Json5,
+    _ => FileKind::Json,
   }
+}
 
-  fn is_special_json_file(path: &Path, config: &Configuration) -> bool {
-    let path = path.to_string_lossy();
-    for file_name in &config.json_trailing_comma_files {
-      if path.ends_with(file_name) {
-        return true;
-      }
+/// Gets if the file is one of the `jsonTrailingCommaFiles`, which are treated as JSONC files
+/// for comments and trailing commas, but are otherwise formatted as JSON.
+fn is_special_json_file(path: &Path, config: &Configuration) -> bool {
+  let path = path.to_string_lossy();
+  for file_name in &config.json_trailing_comma_files {
+    if path.ends_with(file_name) {
+      return true;
     }
-
-    false
   }
 
-  has_jsonc_extension(path) || is_special_json_file(path, config)
+  false
 }
 
 #[cfg(test)]
 mod tests {
   use std::path::PathBuf;
 
+  use crate::configuration::CommentsKind;
   use crate::configuration::ConfigurationBuilder;
+  use crate::configuration::DuplicateKeysKind;
 
   use super::super::configuration::resolve_config;
   use super::*;
@@ -168,19 +589,280 @@ mod tests {
   }
 
   #[test]
-  fn test_is_jsonc_file() {
+  fn test_get_file_kind() {
+    assert_eq!(get_file_kind(&PathBuf::from("/asdf.json")), FileKind::Json);
+    assert_eq!(get_file_kind(&PathBuf::from("/asdf.jsonc")), FileKind::Jsonc);
+    assert_eq!(get_file_kind(&PathBuf::from("/ASDF.JSONC")), FileKind::Jsonc);
+    assert_eq!(get_file_kind(&PathBuf::from("/asdf.json5")), FileKind::Json5);
+    assert_eq!(get_file_kind(&PathBuf::from("/ASDF.JSON5")), FileKind::Json5);
+    assert_eq!(get_file_kind(&PathBuf::from("/tsconfig.json")), FileKind::Json);
+  }
+
+  #[test]
+  fn test_is_special_json_file() {
     let config = ConfigurationBuilder::new()
       .json_trailing_comma_files(vec!["tsconfig.json".to_string(), ".vscode/settings.json".to_string()])
       .build();
-    assert!(!is_jsonc_file(&PathBuf::from("/asdf.json"), &config));
-    assert!(is_jsonc_file(&PathBuf::from("/asdf.jsonc"), &config));
-    assert!(is_jsonc_file(&PathBuf::from("/ASDF.JSONC"), &config));
-    assert!(is_jsonc_file(&PathBuf::from("/tsconfig.json"), &config));
-    assert!(is_jsonc_file(&PathBuf::from("/test/.vscode/settings.json"), &config));
-    assert!(!is_jsonc_file(&PathBuf::from("/test/vscode/settings.json"), &config));
+    assert!(!is_special_json_file(&PathBuf::from("/asdf.json"), &config));
+    assert!(is_special_json_file(&PathBuf::from("/tsconfig.json"), &config));
+    assert!(is_special_json_file(
+      &PathBuf::from("/test/.vscode/settings.json"),
+      &config
+    ));
+    assert!(!is_special_json_file(
+      &PathBuf::from("/test/vscode/settings.json"),
+      &config
+    ));
     if cfg!(windows) {
-      assert!(is_jsonc_file(&PathBuf::from("test\\.vscode\\settings.json"), &config));
+      assert!(is_special_json_file(
+        &PathBuf::from("test\\.vscode\\settings.json"),
+        &config
+      ));
+    }
+  }
+
+  #[test]
+  fn format_range_smallest_node() {
+    let config = ConfigurationBuilder::new().build();
+    let text = "{\n  \"a\": [1,2],\n  \"b\": {\n\"c\":   1,\n\"d\":2}\n}\n";
+    let start = text.find("\"c\"").unwrap();
+    let result = format_range(Path::new("file.json"), text, start..start + 3, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(
+      result.range,
+      text.find("\"c\"").unwrap()..text.find("1,\n").unwrap() + 1
+    );
+    assert_eq!(result.text, "\"c\": 1");
+
+    let start = text.find("\"d\"").unwrap();
+    let result = format_range(Path::new("file.json"), text, start - 4..start, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(
+      result.range,
+      text.find("{\n\"c\"").unwrap()..text.find("2}").unwrap() + 2
+    );
+    assert_eq!(result.text, "{\n    \"c\": 1,\n    \"d\": 2\n  }");
+  }
+
+  #[test]
+  fn format_range_uses_surrounding_text_for_width() {
+    let config = ConfigurationBuilder::new().line_width(30).build();
+    let text = "{\n    // comment\n    \"value\": [  1, 2, 3 ], \"other\": [ 4, 5, 6 ]\n}";
+    let start = text.find("5").unwrap();
+    let result = format_range(Path::new("file.json"), text, start..start, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(&text[result.range.clone()], "[ 4, 5, 6 ]");
+    assert_eq!(result.text, "[\n      4,\n      5,\n      6\n    ]");
+
+    let start = text.find("1").unwrap();
+    let result = format_range(Path::new("file.json"), text, start..start + 1, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(&text[result.range], "[  1, 2, 3 ]");
+    assert_eq!(result.text, "[1, 2, 3]");
+  }
+
+  #[test]
+  fn format_range_no_changes() {
+    let config = ConfigurationBuilder::new().build();
+    let text = "\u{FEFF}{\n  \"a\": [1, 2] // comment\n}\n";
+    let start = text.find('2').unwrap();
+    assert_eq!(
+      format_range(Path::new("file.json"), text, start..start, &config).unwrap(),
+      None
+    );
+    assert_eq!(format_range(Path::new("file.json"), "5", 0..1, &config).unwrap(), None);
+    assert_eq!(
+      format_range(Path::new("file.json"), "{} ", 3..3, &config).unwrap(),
+      None
+    );
+  }
+
+  #[test]
+  fn format_range_bom() {
+    let config = ConfigurationBuilder::new().build();
+    let text = "\u{FEFF}{\"a\":1}";
+    let result = format_range(Path::new("file.json"), text, 4..5, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(result.range, 4..9);
+    assert_eq!(result.text, "\"a\": 1");
+  }
+
+  #[test]
+  fn should_ignore_file() {
+    let config = ConfigurationBuilder::new().build();
+    for text in [
+      "// dprint-ignore-file\n{  }",
+      "\u{FEFF}/* header */\n\n// dprint-ignore-file: vendored\n[1,2]",
+      "/* dprint-ignore-file */ { invalid",
+    ] {
+      assert_eq!(format_text(Path::new("file.json"), text, &config).unwrap(), None);
+      assert_eq!(
+        format_range(Path::new("file.json"), text, 0..text.len(), &config).unwrap(),
+        None
+      );
+    }
+
+    // only comments before the first token
+    let text = "{ // dprint-ignore-file\n}";
+    assert!(format_text(Path::new("file.json"), text, &config).unwrap().is_some());
+    let text = "// dprint-ignore-filed\n{  }";
+    assert!(format_text(Path::new("file.json"), text, &config).unwrap().is_some());
+    let text = "// deno-fmt-ignore-file\n{  }";
+    let config = ConfigurationBuilder::new().deno().build();
+    assert_eq!(format_text(Path::new("file.json"), text, &config).unwrap(), None);
+  }
+
+  #[test]
+  fn should_honor_multiple_ignore_comment_texts() {
+    let config = ConfigurationBuilder::new()
+      .ignore_node_comment_texts(vec!["prettier-ignore".to_string(), "biome-ignore".to_string()])
+      .build();
+    let text = concat!(
+      "{\n",
+      "  // prettier-ignore\n",
+      "  \"a\": [1,    2],\n",
+      "  // biome-ignore lint: reason\n",
+      "  \"b\": [1,    2],\n",
+      "  // dprint-ignore\n",
+      "  \"c\": [1,    2]\n",
+      "}\n",
+    );
+    let result = format_text(Path::new("file.json"), text, &config).unwrap().unwrap();
+    assert_eq!(result, text.replace("\"c\": [1,    2]", "\"c\": [1, 2]"));
+
+    let text = "// biome-ignore-file\n{  }";
+    assert_eq!(format_text(Path::new("file.json"), text, &config).unwrap(), None);
+  }
+
+  #[test]
+  fn should_error_on_comments() {
+    let config = ConfigurationBuilder::new().comments(CommentsKind::Error).build();
+    let message = format_text(Path::new("file.json"), "{\n  \"a\": 1 // comment\n}", &config)
+      .err()
+      .unwrap()
+      .to_string();
+    assert_eq!(
+      message,
+      "Line 2, column 10: Comments are not allowed\n\n    \"a\": 1 // comment\n           ~~~~~~~~~~"
+    );
+    let text = "{\n  // comment\n  \"a\": 1\n}\n";
+    assert_eq!(format_text(Path::new("file.jsonc"), text, &config).unwrap(), None);
+  }
+
+  #[test]
+  fn should_error_on_non_standard_syntax_when_strict() {
+    let config = ConfigurationBuilder::new().strict(true).build();
+    let cases = [
+      (
+        "{ \"a\": 'b' }",
+        "Line 1, column 8: Single-quoted strings are not allowed",
+      ),
+      ("{ a: 1 }", "Line 1, column 3: Expected string for object property"),
+      ("[1, 2,]", "Line 1, column 6: Trailing commas are not allowed"),
+      ("{ \"a\": 1 \"b\": 2 }", "Line 1, column 9: Expected comma"),
+      ("[0xFF]", "Line 1, column 2: Hexadecimal numbers are not allowed"),
+      ("[+1]", "Line 1, column 2: Unary plus on numbers is not allowed"),
+      ("[1] // a", "Line 1, column 5: Comments are not allowed"),
+      ("[NaN]", "Line 1, column 2: Unexpected token"),
+    ];
+    for (text, expected) in cases {
+      let message = format_text(Path::new("file.json"), text, &config)
+        .err()
+        .unwrap()
+        .to_string();
+      assert_eq!(message.lines().next().unwrap(), expected, "{}", text);
     }
+
+    // only json files are strict
+    assert!(format_text(Path::new("file.jsonc"), "[1, 2,] // a", &config).is_ok());
+    assert!(format_text(Path::new("file.json5"), "{ a: 'b' }", &config).is_ok());
+    // removed comments don't error
+    let config = ConfigurationBuilder::new()
+      .strict(true)
+      .comments(CommentsKind::Remove)
+      .build();
+    assert_eq!(
+      format_text(Path::new("file.json"), "[1] // a\n", &config)
+        .unwrap()
+        .unwrap(),
+      "[1]\n"
+    );
+  }
+
+  #[test]
+  fn should_use_parse_options() {
+    let config = ConfigurationBuilder::new()
+      .parse_allow_trailing_commas(false)
+      .parse_allow_single_quoted_strings(false)
+      .build();
+    for path in ["file.json", "file.jsonc", "file.json5"] {
+      let message = format_text(Path::new(path), "[1, 2,]", &config)
+        .err()
+        .unwrap()
+        .to_string();
+      assert_eq!(
+        message.lines().next().unwrap(),
+        "Line 1, column 6: Trailing commas are not allowed"
+      );
+      let message = format_text(Path::new(path), "['a']", &config)
+        .err()
+        .unwrap()
+        .to_string();
+      assert_eq!(
+        message.lines().next().unwrap(),
+        "Line 1, column 2: Single-quoted strings are not allowed"
+      );
+    }
+    assert!(format_text(Path::new("file.json"), "{ a: 0xFF, b: +1 } // c", &config).is_ok());
+
+    let config = ConfigurationBuilder::new().parse_allow_comments(false).build();
+    let message = format_text(Path::new("file.jsonc"), "[1] // a", &config)
+      .err()
+      .unwrap()
+      .to_string();
+    assert_eq!(
+      message.lines().next().unwrap(),
+      "Line 1, column 5: Comments are not allowed"
+    );
+  }
+
+  #[test]
+  fn should_error_on_duplicate_keys() {
+    let config = ConfigurationBuilder::new()
+      .object_duplicate_keys(DuplicateKeysKind::Error)
+      .build();
+    let text = "{\n  \"a\": { \"b\": 1 },\n  \"\\u0061\": 2\n}";
+    let err = format_text(Path::new("file.json"), text, &config).err().unwrap();
+    assert_eq!(
+      err.to_string(),
+      "Line 3, column 3: Duplicate property name \"a\"\n\n    \"\\u0061\": 2\n    ~~~~~~~~"
+    );
+    assert_eq!(err.message(), "Duplicate property name \"a\"");
+    assert_eq!(err.range(), 23..31);
+    assert!(err.parse_error().is_none());
+    assert!(std::error::Error::source(&err).is_none());
+
+    assert!(format_text(Path::new("file.json"), "[{ \"a\": 1 }, { \"a\": 2 }]", &config).is_ok());
+    let err = format_text(Path::new("file.json"), "{ &*&* }", &config).err().unwrap();
+    assert_eq!(err.message(), "Unexpected token");
+    assert!(err.parse_error().is_some());
+  }
+
+  #[test]
+  fn format_range_remove_comments() {
+    let config = ConfigurationBuilder::new().comments(CommentsKind::Remove).build();
+    let text = "{\n  // a\n  \"a\": [1, /* b */ 2], // c\n  \"b\": 2\n}\n";
+    let start = text.find('[').unwrap();
+    let result = format_range(Path::new("file.json"), text, start..start, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(&text[result.range], "[1, /* b */ 2]");
+    assert_eq!(result.text, "[1, 2]");
   }
 
   #[test]
@@ -192,4 +874,59 @@ mod tests {
       assert_eq!(output_text, "{}\n");
     }
   }
+
+  #[test]
+  fn should_parse_json5_syntax_jsonc_parser_does_not_support() {
+    let global_config = GlobalConfiguration::default();
+    let config = resolve_config(ConfigKeyMap::new(), &global_config).config;
+    let input_text = "{a: 'x\\\r\ny', b: 'x\\\u{2028}y', c: [.5, 5., NaN], Infinity: -Infinity}";
+    let output_text = format_text(Path::new("file.json5"), input_text, &config)
+      .unwrap()
+      .unwrap();
+    assert_eq!(
+      output_text,
+      "{\n  a: 'x\\\ny',\n  b: 'x\\\u{2028}y',\n  c: [.5, 5., NaN],\n  Infinity: -Infinity\n}\n"
+    );
+    // the text is only replaced for json5 files
+    assert!(format_text(Path::new("file.jsonc"), "{ \"a\": NaN }", &config).is_err());
+  }
+
+  #[test]
+  fn should_find_duplicate_keys() {
+    let config = ConfigurationBuilder::new()
+      .object_duplicate_keys(DuplicateKeysKind::KeepFirst)
+      .build();
+    let text = "\u{FEFF}{ \"a\": 1, \"b\": { \"c\": 2, c: 3 }, \"\\u0061\": 4 }";
+    let duplicate_keys = find_duplicate_keys(Path::new("file.jsonc"), text, &config).unwrap();
+    assert_eq!(
+      duplicate_keys
+        .iter()
+        .map(|key| (key.name.as_str(), &text[key.range.clone()]))
+        .collect::<Vec<_>>(),
+      vec![("c", "c"), ("a", "\"\\u0061\"")]
+    );
+    assert_eq!(
+      duplicate_keys[0].diagnostic.lines().next().unwrap(),
+      "Line 1, column 26: Duplicate property name \"c\""
+    );
+    // the properties are still removed when formatting
+    assert_eq!(
+      format_text(Path::new("file.jsonc"), text, &config).unwrap().unwrap(),
+      "{ \"a\": 1, \"b\": { \"c\": 2 } }\n"
+    );
+    assert!(find_duplicate_keys(Path::new("file.json"), "{ \"a\": ", &config).is_err());
+  }
+
+  #[test]
+  fn should_show_original_json5_text_in_diagnostics() {
+    let config = ConfigurationBuilder::new().build();
+    let message = format_text(Path::new("file.json5"), "[Infinity, .5,,]", &config)
+      .err()
+      .unwrap()
+      .to_string();
+    assert_eq!(
+      message,
+      "Line 1, column 15: Unexpected comma\n\n  [Infinity, .5,,]\n                ~"
+    );
+  }
 }
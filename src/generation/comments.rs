@@ -0,0 +1,340 @@
+use std::borrow::Cow;
+
+/// Gets the text to use after the slashes of a line comment for a block comment's text
+/// or `None` when the block comment can't be written as a line comment.
+pub fn block_to_line_text(text: &str) -> Option<String> {
+  // leave multi-line and JSDoc style (`/** a */`) comments alone
+  if text.contains('\n') || text.starts_with('*') {
+    return None;
+  }
+  let text = text.trim();
+  if text.is_empty() {
+    None
+  } else {
+    Some(format!(" {}", text))
+  }
+}
+
+/// Gets the text to use between the `/*` and `*/` of a block comment for a line comment's text
+/// or `None` when the line comment can't be written as a block comment.
+pub fn line_to_block_text(text: &str) -> Option<String> {
+  // leave triple slash comments (`/// a`) alone
+  let text = text.trim();
+  if text.is_empty() || text.starts_with('/') || text.contains("*/") {
+    None
+  } else {
+    Some(format!(" {} ", text))
+  }
+}
+
+/// Surrounds the text of a single line block comment with a single space.
+pub fn with_space_inside(text: &str) -> Cow<'_, str> {
+  let trimmed_text = text.trim();
+  if text.contains('\n') || text.starts_with('*') || trimmed_text.is_empty() {
+    return Cow::Borrowed(text);
+  }
+  let text_with_spaces = format!(" {} ", trimmed_text);
+  if text_with_spaces == text {
+    Cow::Borrowed(text)
+  } else {
+    Cow::Owned(text_with_spaces)
+  }
+}
+
+/// A word of comment text along with the whitespace before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentWord<'a> {
+  pub whitespace_before: &'a str,
+  pub text: &'a str,
+}
+
+/// Splits comment text into the words that its lines may be wrapped between.
+///
+/// Code spans (ex. `` `a b` ``) are kept as a single word and URLs are never split
+/// since they don't contain whitespace.
+pub fn split_words(text: &str) -> Vec<CommentWord<'_>> {
+  let mut words = Vec::new();
+  let mut rest = text;
+  loop {
+    let word_text = rest.trim_start();
+    if word_text.is_empty() {
+      return words;
+    }
+    let whitespace_before = &rest[..rest.len() - word_text.len()];
+    let first_whitespace_index = word_text.find(char::is_whitespace).unwrap_or(word_text.len());
+    let mut is_in_code_span = false;
+    let mut word_end = None;
+    for (i, c) in word_text.char_indices() {
+      if c == '`' {
+        is_in_code_span = !is_in_code_span;
+      } else if c.is_whitespace() && !is_in_code_span {
+        word_end = Some(i);
+        break;
+      }
+    }
+    // an unclosed code span isn't a code span
+    let word_end = match word_end {
+      Some(index) => index,
+      None if is_in_code_span => first_whitespace_index,
+      None => word_text.len(),
+    };
+    words.push(CommentWord {
+      whitespace_before,
+      text: &word_text[..word_end],
+    });
+    rest = &word_text[word_end..];
+  }
+}
+
+/// Gets the width of the list item marker and the space after it (ex. `- ` or `1. `) that the text starts with.
+pub fn get_list_marker_width(text: &str) -> Option<usize> {
+  let marker_len = if text.starts_with(['-', '*', '+']) {
+    1
+  } else {
+    let digit_count = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digit_count == 0 || !text[digit_count..].starts_with(['.', ')']) {
+      return None;
+    }
+    digit_count + 1
+  };
+  text[marker_len..].starts_with(' ').then_some(marker_len + 1)
+}
+
+/// A line of a JSDoc style block comment (ex. `/** ... */`) excluding the leading `*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsDocLine<'a> {
+  /// Prose that may be wrapped.
+  Paragraph {
+    /// The indentation of the first line.
+    indent: usize,
+    /// The indentation of the lines after the first (ex. to align with the text of a list item).
+    hanging_indent: usize,
+    words: Vec<CommentWord<'a>>,
+  },
+  /// A line to keep as is (ex. within a code block).
+  Verbatim(&'a str),
+}
+
+/// Parses the text of a multi-line JSDoc style block comment into its lines and paragraphs
+/// or returns `None` when it's not a JSDoc style comment (ex. a line doesn't start with a `*`).
+pub fn parse_js_doc_lines(text: &str) -> Option<Vec<JsDocLine<'_>>> {
+  let text = text.strip_prefix('*')?;
+  if text.starts_with('*') || !text.contains('\n') {
+    return None;
+  }
+
+  let mut contents = Vec::new();
+  for (i, line) in text.split('\n').enumerate() {
+    let content = if i == 0 {
+      line
+    } else {
+      let line = line.trim_start();
+      match line.strip_prefix('*') {
+        Some(content) => content,
+        None if line.trim_end().is_empty() => "",
+        None => return None,
+      }
+    };
+    let content = content.trim_end();
+    contents.push(content.strip_prefix(' ').unwrap_or(content));
+  }
+  if contents.first() == Some(&"") {
+    contents.remove(0);
+  }
+  if contents.last() == Some(&"") {
+    contents.pop();
+  }
+
+  let mut lines = Vec::with_capacity(contents.len());
+  let mut is_in_code_block = false;
+  for content in contents {
+    let trimmed_content = content.trim_start();
+    let indent = content.len() - trimmed_content.len();
+    if trimmed_content.starts_with("```") {
+      is_in_code_block = !is_in_code_block;
+      lines.push(JsDocLine::Verbatim(content));
+      continue;
+    }
+    if is_in_code_block || trimmed_content.is_empty() || trimmed_content.starts_with(['|', '#']) {
+      lines.push(JsDocLine::Verbatim(content));
+      continue;
+    }
+
+    let list_marker_width = get_list_marker_width(trimmed_content);
+    let mut words = split_words(trimmed_content);
+    if list_marker_width.is_none()
+      && !trimmed_content.starts_with('@')
+      && let Some(JsDocLine::Paragraph {
+        hanging_indent,
+        words: paragraph_words,
+        ..
+      }) = lines.last_mut()
+      && *hanging_indent == indent
+    {
+      // the line break becomes a space that may be wrapped at
+      words[0].whitespace_before = " ";
+      paragraph_words.extend(words);
+    } else if indent == 0 || list_marker_width.is_some() {
+      lines.push(JsDocLine::Paragraph {
+        indent,
+        hanging_indent: indent + list_marker_width.unwrap_or(0),
+        words,
+      });
+    } else {
+      lines.push(JsDocLine::Verbatim(content));
+    }
+  }
+  Some(lines)
+}
+
+/// Gets the indentation width of the line the position is on.
+pub fn get_line_indent_width(text: &str, pos: usize, indent_width: u8) -> usize {
+  let line_start = text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+  let line_text = &text[line_start..pos];
+  let line_text = line_text.trim_start_matches([' ', '\t']);
+  get_indent_width(&text[line_start..pos - line_text.len()], indent_width)
+}
+
+/// Removes up to the provided width of indentation from the start of the line.
+pub fn strip_indent(line: &str, width: usize, indent_width: u8) -> &str {
+  let mut current_width = 0;
+  for (i, c) in line.char_indices() {
+    if current_width >= width || !matches!(c, ' ' | '\t') {
+      return &line[i..];
+    }
+    current_width = get_indent_width(&line[..i + 1], indent_width);
+  }
+  ""
+}
+
+fn get_indent_width(indent_text: &str, indent_width: u8) -> usize {
+  let indent_width = std::cmp::max(indent_width as usize, 1);
+  let mut width = 0;
+  for c in indent_text.chars() {
+    match c {
+      '\t' => width += indent_width - width % indent_width,
+      _ => width += 1,
+    }
+  }
+  width
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn converts_block_to_line() {
+    assert_eq!(block_to_line_text(" a "), Some(" a".to_string()));
+    assert_eq!(block_to_line_text("a"), Some(" a".to_string()));
+    assert_eq!(block_to_line_text("* doc "), None);
+    assert_eq!(block_to_line_text("a\nb"), None);
+    assert_eq!(block_to_line_text("  "), None);
+  }
+
+  #[test]
+  fn converts_line_to_block() {
+    assert_eq!(line_to_block_text(" a"), Some(" a ".to_string()));
+    assert_eq!(line_to_block_text("a  "), Some(" a ".to_string()));
+    assert_eq!(line_to_block_text("/ triple"), None);
+    assert_eq!(line_to_block_text(" a */ b"), None);
+    assert_eq!(line_to_block_text(""), None);
+  }
+
+  #[test]
+  fn splits_words() {
+    let words = |text| {
+      split_words(text)
+        .into_iter()
+        .map(|w| (w.whitespace_before, w.text))
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(words(" a  b "), vec![(" ", "a"), ("  ", "b")]);
+    assert_eq!(
+      words("see `a b` at https://example.com/a?b=c"),
+      vec![
+        ("", "see"),
+        (" ", "`a b`"),
+        (" ", "at"),
+        (" ", "https://example.com/a?b=c")
+      ]
+    );
+    assert_eq!(words("unclosed `a b"), vec![("", "unclosed"), (" ", "`a"), (" ", "b")]);
+    assert_eq!(words(""), vec![]);
+  }
+
+  #[test]
+  fn gets_list_marker_width() {
+    assert_eq!(get_list_marker_width("- a"), Some(2));
+    assert_eq!(get_list_marker_width("* a"), Some(2));
+    assert_eq!(get_list_marker_width("10. a"), Some(4));
+    assert_eq!(get_list_marker_width("1) a"), Some(3));
+    assert_eq!(get_list_marker_width("-a"), None);
+    assert_eq!(get_list_marker_width("1 a"), None);
+    assert_eq!(get_list_marker_width("a"), None);
+  }
+
+  #[test]
+  fn parses_js_doc_lines() {
+    let text = "*\n   * Some text\n   * that continues.\n   *\n   * - item\n   *   continued\n   * @param a\n   * ```\n   * code  here\n   * ```\n   *     indented\n   ";
+    let lines = parse_js_doc_lines(text).unwrap();
+    let paragraph = |indent, hanging_indent, text| JsDocLine::Paragraph {
+      indent,
+      hanging_indent,
+      words: {
+        let mut words = split_words(text);
+        for word in words.iter_mut().skip(1) {
+          word.whitespace_before = " ";
+        }
+        words
+      },
+    };
+    assert_eq!(
+      lines,
+      vec![
+        paragraph(0, 0, "Some text that continues."),
+        JsDocLine::Verbatim(""),
+        paragraph(0, 2, "- item continued"),
+        paragraph(0, 0, "@param a"),
+        JsDocLine::Verbatim("```"),
+        JsDocLine::Verbatim("code  here"),
+        JsDocLine::Verbatim("```"),
+        JsDocLine::Verbatim("    indented"),
+      ]
+    );
+
+    assert_eq!(parse_js_doc_lines("* single line "), None);
+    assert_eq!(parse_js_doc_lines(" not\n * js doc"), None);
+    assert_eq!(parse_js_doc_lines("*\n not a star\n"), None);
+    assert_eq!(parse_js_doc_lines("**\n * banner\n"), None);
+  }
+
+  #[test]
+  fn gets_line_indent_width() {
+    let text = "{\n    /* a */\n\t  /* b */ /* c */";
+    assert_eq!(get_line_indent_width(text, 0, 4), 0);
+    assert_eq!(get_line_indent_width(text, 6, 4), 4);
+    assert_eq!(get_line_indent_width(text, 17, 4), 6);
+    assert_eq!(get_line_indent_width(text, 25, 4), 6);
+  }
+
+  #[test]
+  fn strips_indent() {
+    assert_eq!(strip_indent("    * a", 2, 4), "  * a");
+    assert_eq!(strip_indent(" * a", 2, 4), "* a");
+    assert_eq!(strip_indent("\t* a", 2, 4), "* a");
+    assert_eq!(strip_indent("\t\t* a", 4, 4), "\t* a");
+    assert_eq!(strip_indent("   ", 2, 4), " ");
+    assert_eq!(strip_indent("  ", 4, 4), "");
+  }
+
+  #[test]
+  fn adds_space_inside() {
+    assert_eq!(with_space_inside("a"), " a ");
+    assert_eq!(with_space_inside("   a b  "), " a b ");
+    assert!(matches!(with_space_inside(" a "), Cow::Borrowed(_)));
+    assert_eq!(with_space_inside("* doc"), "* doc");
+    assert_eq!(with_space_inside("a\n b"), "a\n b");
+    assert_eq!(with_space_inside(""), "");
+  }
+}
@@ -1,17 +1,63 @@
 use super::super::configuration::Configuration;
+use super::super::configuration::QuotePropsKind;
+use super::super::configuration::QuoteStyleKind;
 use super::token_finder::TokenFinder;
+use dprint_core::formatting::ColumnNumber;
+use dprint_core::formatting::LineNumber;
 use jsonc_parser::CommentMap;
 use jsonc_parser::ast::*;
 use jsonc_parser::common::Ranged;
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::rc::Rc;
 use text_lines::TextLines;
 
+/// The kind of file being formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+  Json,
+  Jsonc,
+  Json5,
+}
+
+/// The column information used to align a trailing line comment with the others in its group.
+pub struct TrailingCommentAlignment {
+  /// The column the comment would start at without alignment.
+  pub column: ColumnNumber,
+  /// The columns of all the comments in the group.
+  pub group_columns: Rc<Vec<ColumnNumber>>,
+}
+
+/// The information used to align the value of a property with the others in its group.
+pub struct ValueAlignment {
+  /// The number of spaces to add after the colon.
+  pub padding: usize,
+  /// The line of the object's open brace, so that values are only aligned when the object is multi-line.
+  pub open_brace_line: LineNumber,
+}
+
 pub struct Context<'a, 'b> {
   pub config: &'b Configuration,
   pub text: &'b str,
   pub text_info: TextLines,
-  pub is_jsonc: bool,
+  pub file_kind: FileKind,
+  /// Whether the file is one of the `jsonTrailingCommaFiles`, which only affects trailing commas.
+  pub is_special_json_file: bool,
+  /// Whether the property names of the current object should be quoted when `quoteProps` is `consistent`.
+  pub quote_prop_names: Option<bool>,
   pub handled_comments: HashSet<usize>,
+  /// The end of the last line comment joined into a wrapped line comment, keyed by the wrapped comment's start.
+  pub joined_comment_ends: HashMap<usize, usize>,
+  /// Trailing line comments to align when `comment.alignTrailing` is enabled, keyed by comment start.
+  pub aligned_trailing_comments: HashMap<usize, TrailingCommentAlignment>,
+  /// The column widths of the rows of matrices to align when `array.alignMatrix` is enabled, keyed by row start.
+  pub matrix_column_widths: HashMap<usize, Rc<Vec<usize>>>,
+  /// The padded property texts of the rows of tables when `array.tableLayout` is enabled, keyed by row start.
+  pub table_rows: HashMap<usize, Vec<String>>,
+  /// Property values to align when `object.alignValues` is enabled, keyed by property start.
+  pub aligned_values: HashMap<usize, ValueAlignment>,
+  /// The start line of the removed duplicate properties before a property, keyed by property start.
+  pub removed_duplicate_start_lines: HashMap<usize, usize>,
   pub parent_stack: Vec<Node<'a, 'a>>,
   pub current_node: Option<Node<'a, 'a>>,
   pub comments: &'b CommentMap<'a>,
@@ -19,6 +65,26 @@ pub struct Context<'a, 'b> {
 }
 
 impl<'a, 'b> Context<'a, 'b> {
+  /// Gets if the file has a JSONC or JSON5 extension, which supports extensions to JSON such as
+  /// unquoted property names and single quoted strings.
+  pub fn is_jsonc(&self) -> bool {
+    self.file_kind != FileKind::Json
+  }
+
+  pub fn quote_props(&self) -> QuotePropsKind {
+    self.config.quote_props.unwrap_or(match self.file_kind {
+      FileKind::Json5 => QuotePropsKind::Preserve,
+      FileKind::Json | FileKind::Jsonc => QuotePropsKind::Always,
+    })
+  }
+
+  pub fn quote_style(&self) -> QuoteStyleKind {
+    self.config.quote_style.unwrap_or(match self.file_kind {
+      FileKind::Json5 => QuoteStyleKind::Preserve,
+      FileKind::Json | FileKind::Jsonc => QuoteStyleKind::AlwaysDouble,
+    })
+  }
+
   pub fn has_handled_comment(&self, comment: &Comment) -> bool {
     self.handled_comments.contains(&comment.start())
   }
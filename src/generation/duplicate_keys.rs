@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use jsonc_parser::ast::*;
+
+use crate::configuration::DuplicateKeysKind;
+
+/// Finds the properties, in document order, that have the same name as a previous property of their object.
+pub fn find_duplicate_keys<'a, 'b>(value: &'b Value<'a>) -> Vec<&'b ObjectPropName<'a>> {
+  let mut duplicates = Vec::new();
+  add_duplicate_keys(value, &mut duplicates);
+  duplicates
+}
+
+fn add_duplicate_keys<'a, 'b>(value: &'b Value<'a>, duplicates: &mut Vec<&'b ObjectPropName<'a>>) {
+  match value {
+    Value::Object(obj) => {
+      let mut names = HashSet::with_capacity(obj.properties.len());
+      for prop in obj.properties.iter() {
+        if !names.insert(prop.name.as_str()) {
+          duplicates.push(&prop.name);
+        }
+        add_duplicate_keys(&prop.value, duplicates);
+      }
+    }
+    Value::Array(array) => {
+      for element in array.elements.iter() {
+        add_duplicate_keys(element, duplicates);
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Gets the indexes of the properties to remove so that only one property with each name remains.
+pub fn get_removed_duplicates(obj: &Object, kind: DuplicateKeysKind) -> HashSet<usize> {
+  let mut removed = HashSet::new();
+  match kind {
+    DuplicateKeysKind::Allow | DuplicateKeysKind::Error => {}
+    DuplicateKeysKind::KeepFirst => {
+      let mut names = HashSet::with_capacity(obj.properties.len());
+      for (i, prop) in obj.properties.iter().enumerate() {
+        if !names.insert(prop.name.as_str()) {
+          removed.insert(i);
+        }
+      }
+    }
+    DuplicateKeysKind::KeepLast => {
+      let mut last_indexes = HashMap::with_capacity(obj.properties.len());
+      for (i, prop) in obj.properties.iter().enumerate() {
+        if let Some(previous_index) = last_indexes.insert(prop.name.as_str(), i) {
+          removed.insert(previous_index);
+        }
+      }
+    }
+  }
+  removed
+}
+
+#[cfg(test)]
+mod tests {
+  use jsonc_parser::parse_to_ast;
+
+  use super::*;
+
+  #[test]
+  fn finds_duplicate_keys() {
+    let find = |text: &str| {
+      let result = parse_to_ast(text, &Default::default(), &Default::default()).unwrap();
+      find_duplicate_keys(result.value.as_ref().unwrap())
+        .into_iter()
+        .map(|name| name.as_str().to_string())
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(find(r#"{ "a": 1, "b": 2 }"#), Vec::<String>::new());
+    assert_eq!(find(r#"{ "a": 1, "a": 2 }"#), vec!["a"]);
+    assert_eq!(find(r#"{ "a": { "b": 1, b: 2 }, "a": 3 }"#), vec!["b", "a"]);
+    assert_eq!(
+      find(r#"[{ "a": 1 }, { "a": 2, "c": [{ "d": 1, "d": 2, "d": 3 }] }]"#),
+      vec!["d", "d"]
+    );
+  }
+
+  #[test]
+  fn gets_removed_duplicates() {
+    let result = parse_to_ast(
+      r#"{ "a": 1, "b": 2, "a": 3, "a": 4 }"#,
+      &Default::default(),
+      &Default::default(),
+    )
+    .unwrap();
+    let Some(Value::Object(obj)) = &result.value else {
+      unreachable!();
+    };
+    let get = |kind| {
+      let mut indexes = get_removed_duplicates(obj, kind).into_iter().collect::<Vec<_>>();
+      indexes.sort();
+      indexes
+    };
+    assert_eq!(get(DuplicateKeysKind::Allow), Vec::<usize>::new());
+    assert_eq!(get(DuplicateKeysKind::KeepFirst), vec![2, 3]);
+    assert_eq!(get(DuplicateKeysKind::KeepLast), vec![0, 2]);
+  }
+}
@@ -1,17 +1,32 @@
 use super::super::configuration::Configuration;
+use super::comments;
 use super::context::Context;
+use super::context::FileKind;
+use super::context::TrailingCommentAlignment;
+use super::context::ValueAlignment;
+use super::duplicate_keys::get_removed_duplicates;
+use super::numbers;
+use super::range::get_range_node;
+use super::sort_keys::compare_keys;
+use super::strings;
 use super::token_finder::TokenFinder;
+use dprint_core::formatting::conditions::if_true;
 use dprint_core::formatting::conditions::if_true_or;
 use dprint_core::formatting::ir_helpers::SingleLineOptions;
 use dprint_core::formatting::*;
 use dprint_core_macros::sc;
+use jsonc_parser::CommentMap;
 use jsonc_parser::ast::*;
 use jsonc_parser::common::Range;
 use jsonc_parser::common::Ranged;
+use jsonc_parser::tokens::Token;
 use jsonc_parser::tokens::TokenAndRange;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::rc::Rc;
 use text_lines::TextLines;
+use unicode_width::UnicodeWidthStr;
 
 use crate::configuration::*;
 
@@ -19,7 +34,74 @@ pub fn generate(
   parse_result: jsonc_parser::ParseResult,
   text: &str,
   config: &Configuration,
-  is_jsonc: bool,
+  file_kind: FileKind,
+  is_special_json_file: bool,
+) -> PrintItems {
+  with_context(
+    parse_result,
+    text,
+    config,
+    file_kind,
+    is_special_json_file,
+    |node_value, comments, context| {
+      let mut items = PrintItems::new();
+      if let Some(node_value) = node_value {
+        items.extend(gen_node(node_value.into(), context));
+        items.extend(gen_trailing_comments_as_statements(node_value, context));
+      } else if let Some(comments) = comments.get(&0) {
+        items.extend(gen_comments_as_statements(comments.iter(), None, context));
+      }
+      items.push_condition(conditions::if_true(
+        "endOfFileNewLine",
+        Rc::new(|context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0)),
+        Signal::NewLine.into(),
+      ));
+
+      items
+    },
+  )
+}
+
+/// Generates the object, array, or object property found by `get_range_node` for the
+/// provided range. Comments surrounding the node are not included.
+pub fn generate_node(
+  parse_result: jsonc_parser::ParseResult,
+  text: &str,
+  config: &Configuration,
+  file_kind: FileKind,
+  is_special_json_file: bool,
+  range: Range,
+) -> PrintItems {
+  with_context(
+    parse_result,
+    text,
+    config,
+    file_kind,
+    is_special_json_file,
+    |node_value, comments, context| {
+      let Some(node) = node_value.and_then(|value| get_range_node(value, range)) else {
+        return PrintItems::new();
+      };
+      // the comments before and after the node are outside the range being formatted
+      for pos in [node.start(), node.end()] {
+        if let Some(comments) = comments.get(&pos) {
+          for comment in comments.iter() {
+            context.mark_comment_handled(comment);
+          }
+        }
+      }
+      gen_node(node, context)
+    },
+  )
+}
+
+fn with_context<'a>(
+  parse_result: jsonc_parser::ParseResult<'a>,
+  text: &str,
+  config: &Configuration,
+  file_kind: FileKind,
+  is_special_json_file: bool,
+  gen_items: impl for<'b> FnOnce(Option<&'b Value<'b>>, &'b CommentMap<'b>, &mut Context<'b, 'b>) -> PrintItems,
 ) -> PrintItems {
   let comments = parse_result.comments.unwrap();
   let tokens = parse_result.tokens.unwrap();
@@ -29,28 +111,23 @@ pub fn generate(
     config,
     text,
     text_info,
-    is_jsonc,
+    file_kind,
+    is_special_json_file,
+    quote_prop_names: None,
     handled_comments: HashSet::new(),
+    joined_comment_ends: HashMap::new(),
+    aligned_trailing_comments: HashMap::new(),
+    matrix_column_widths: HashMap::new(),
+    table_rows: HashMap::new(),
+    aligned_values: HashMap::new(),
+    removed_duplicate_start_lines: HashMap::new(),
     parent_stack: Vec::new(),
     current_node: None,
     comments: &comments,
     token_finder: TokenFinder::new(&tokens),
   };
 
-  let mut items = PrintItems::new();
-  if let Some(node_value) = &node_value {
-    items.extend(gen_node(node_value.into(), &mut context));
-    items.extend(gen_trailing_comments_as_statements(node_value, &mut context));
-  } else if let Some(comments) = comments.get(&0) {
-    items.extend(gen_comments_as_statements(comments.iter(), None, &mut context));
-  }
-  items.push_condition(conditions::if_true(
-    "endOfFileNewLine",
-    Rc::new(|context| Some(context.writer_info.column_number > 0 || context.writer_info.line_number > 0)),
-    Signal::NewLine.into(),
-  ));
-
-  items
+  gen_items(node_value.as_ref(), &comments, &mut context)
 }
 
 fn gen_node<'a>(node: Node<'a, 'a>, context: &mut Context<'a, '_>) -> PrintItems {
@@ -108,7 +185,9 @@ fn gen_node_with_inner<'a>(
       Node::Array(node) => gen_array(node, context),
       Node::BooleanLit(node) => node.value.to_string().into(),
       Node::NullKeyword(_) => "null".into(),
-      Node::NumberLit(node) => node.value.to_string().into(),
+      Node::NumberLit(node) => numbers::format_number(get_number_text(node, context), context.config)
+        .into_owned()
+        .into(),
       Node::Object(node) => gen_object(node, context),
       Node::ObjectProp(node) => gen_object_prop(node, context),
       Node::StringLit(node) => gen_string_lit(node, context),
@@ -118,26 +197,63 @@ fn gen_node_with_inner<'a>(
 }
 
 fn gen_array<'a>(node: &'a Array<'a>, context: &mut Context<'a, '_>) -> PrintItems {
-  let force_multi_lines = !context.config.array_prefer_single_line
+  if let Some(column_widths) = context.matrix_column_widths.remove(&node.start()) {
+    return gen_matrix_row(node, &column_widths, context);
+  }
+
+  let ignored_ranges = get_ignored_ranges(node.elements.iter().map(|e| e.range()), context);
+  let keep_multi_line = !context.config.array_prefer_single_line
     && (should_break_up_single_line(node, context)
+      || has_multi_line_ignored_range(&ignored_ranges, context)
       || context.text_info.line_index(node.start())
         < node
           .elements
           .first()
           .map(|p| context.text_info.line_index(p.start()))
           .unwrap_or_else(|| context.text_info.line_index(node.start())));
+  let fill = context.config.array_fill && ignored_ranges.is_empty() && is_fillable_array(node, context);
+  let matrix_column_widths = if context.config.array_align_matrix && ignored_ranges.is_empty() {
+    get_matrix_column_widths(node, keep_multi_line, context)
+  } else {
+    None
+  };
+  let is_matrix = matrix_column_widths.is_some();
+  if let Some(column_widths) = matrix_column_widths {
+    let column_widths = Rc::new(column_widths);
+    for element in node.elements.iter() {
+      context
+        .matrix_column_widths
+        .insert(element.start(), column_widths.clone());
+    }
+  }
+  let table_rows = if context.config.array_table_layout && ignored_ranges.is_empty() {
+    get_table_rows(node, keep_multi_line, context)
+  } else {
+    None
+  };
+  let is_table = table_rows.is_some();
+  for (element, row) in node.elements.iter().zip(table_rows.into_iter().flatten()) {
+    context.table_rows.insert(element.start(), row);
+  }
+  let force_multi_lines = is_matrix || is_table || keep_multi_line;
 
   gen_surrounded_by_tokens(
     |context| {
+      if fill {
+        return gen_filled_values(node, force_multi_lines, context);
+      }
       let mut items = PrintItems::new();
       items.extend(gen_comma_separated_values(
         GenCommaSeparatedValuesOptions {
           nodes: node.elements.iter().map(|x| Some(x.into())).collect(),
+          last_value_range: node.elements.last().map(|x| x.range()),
+          lines_spans: None,
+          ignored_ranges,
           prefer_hanging: false,
           force_use_new_lines: force_multi_lines,
           allow_blank_lines: true,
-          single_line_space_at_start: false,
-          single_line_space_at_end: false,
+          single_line_space_at_start: context.config.array_space_inside,
+          single_line_space_at_end: context.config.array_space_inside,
           custom_single_line_separator: None,
           multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
           force_possible_newline_at_start: false,
@@ -157,9 +273,337 @@ fn gen_array<'a>(node: &'a Array<'a>, context: &mut Context<'a, '_>) -> PrintIte
   )
 }
 
+/// Generates the elements of an array as a single value so that, when multi-line, each line
+/// is filled with as many elements as fit within the line width.
+fn gen_filled_values<'a>(node: &'a Array<'a>, force_use_new_lines: bool, context: &mut Context<'a, '_>) -> PrintItems {
+  let use_trailing_comma = should_use_trailing_comma(node.elements.last().map(|e| e.range()), context);
+  let indent_width = context.config.indent_width;
+  let space_inside = context.config.array_space_inside;
+  ir_helpers::gen_separated_values(
+    |is_multi_line_ref| {
+      let mut items = PrintItems::new();
+      for (i, element) in node.elements.iter().enumerate() {
+        if i > 0 {
+          items.push_signal(Signal::SpaceOrNewLine);
+        }
+        items.extend(gen_node(element.into(), context));
+        if i < node.elements.len() - 1 {
+          items.push_sc(sc!(","));
+        } else if use_trailing_comma {
+          let is_multi_line = is_multi_line_ref.create_resolver();
+          items.push_condition(if_true("is_multi_line", is_multi_line, ",".into()));
+        }
+      }
+      vec![ir_helpers::GeneratedValue::from_items(items)]
+    },
+    ir_helpers::GenSeparatedValuesOptions {
+      prefer_hanging: false,
+      force_use_new_lines,
+      allow_blank_lines: false,
+      single_line_options: SingleLineOptions {
+        space_at_start: space_inside,
+        space_at_end: space_inside,
+        separator: Signal::SpaceOrNewLine.into(),
+      },
+      indent_width,
+      multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
+      force_possible_newline_at_start: false,
+    },
+  )
+  .items
+}
+
+/// The maximum width of a string, including its quotes, in an array whose elements fill each line.
+const MAX_FILL_STRING_WIDTH: usize = 20;
+
+/// Gets if the array only has primitive values and short strings without comments, so its elements may fill each line.
+fn is_fillable_array(node: &Array, context: &Context) -> bool {
+  let is_primitive = |element: &Value| match element {
+    Value::StringLit(string) => string.text(context.text).width() <= MAX_FILL_STRING_WIDTH,
+    Value::NumberLit(_) | Value::BooleanLit(_) | Value::NullKeyword(_) => true,
+    _ => false,
+  };
+  !node.elements.is_empty() && node.elements.iter().all(is_primitive) && !has_element_comments(node, context)
+}
+
+/// Gets if there are any comments between the brackets of the array.
+fn has_element_comments(node: &Array, context: &Context) -> bool {
+  // comments are keyed by the positions of the surrounding tokens
+  node
+    .elements
+    .iter()
+    .flat_map(|element| [element.start(), element.end()])
+    .chain([node.end() - 1])
+    .any(|pos| context.comments.contains_key(&pos))
+}
+
+/// Measures the width of each column when the array is a matrix of numbers, meaning it
+/// has at least two rows that are same-length number arrays without comments.
+///
+/// The matrix isn't aligned when it would otherwise stay on a single line or an aligned row
+/// would exceed the line width.
+fn get_matrix_column_widths<'a>(
+  node: &'a Array<'a>,
+  is_multi_line: bool,
+  context: &mut Context<'a, '_>,
+) -> Option<Vec<usize>> {
+  if node.elements.len() < 2 || has_element_comments(node, context) {
+    return None;
+  }
+
+  let mut column_widths = Vec::new();
+  let mut numbers_width = 0;
+  for element in node.elements.iter() {
+    let Value::Array(row) = element else {
+      return None;
+    };
+    if row.elements.is_empty()
+      || !column_widths.is_empty() && row.elements.len() != column_widths.len()
+      || has_element_comments(row, context)
+    {
+      return None;
+    }
+    column_widths.resize(row.elements.len(), 0);
+    for (width, value) in column_widths.iter_mut().zip(row.elements.iter()) {
+      let Value::NumberLit(number) = value else {
+        return None;
+      };
+      let number_width = numbers::format_number(get_number_text(number, context), context.config).len();
+      *width = (*width).max(number_width);
+      numbers_width += number_width;
+    }
+  }
+
+  let depth = context
+    .parent_stack
+    .iter()
+    .filter(|n| matches!(n, Node::Array(_) | Node::Object(_)))
+    .count();
+  let indent_width = depth * context.config.indent_width as usize;
+  let line_width = context.config.line_width as usize;
+  let column_count = column_widths.len();
+  let row_count = node.elements.len();
+  // the brackets along with ", " between each value
+  let brackets_width = if context.config.array_space_inside { 4 } else { 2 };
+  if !is_multi_line {
+    let single_line_width =
+      brackets_width * (row_count + 1) + numbers_width + 2 * (column_count - 1) * row_count + 2 * (row_count - 1);
+    if indent_width + get_prop_prefix_width(context) + single_line_width <= line_width {
+      return None;
+    }
+  }
+  // the rows are indented within the array and followed by a comma
+  let row_width = brackets_width + column_widths.iter().sum::<usize>() + 2 * (column_count - 1) + 1;
+  if indent_width + context.config.indent_width as usize + row_width > line_width {
+    return None;
+  }
+  Some(column_widths)
+}
+
+/// Gets the width of the property name and colon that precede the current node when it's
+/// the value of a property.
+fn get_prop_prefix_width(context: &mut Context) -> usize {
+  match context.parent_stack.last() {
+    Some(Node::ObjectProp(prop)) => {
+      let prop = *prop;
+      let colon_width = 1 + context.config.colon_space_before as usize + context.config.colon_space_after as usize;
+      get_prop_name_text(prop, context).width() + colon_width
+    }
+    _ => 0,
+  }
+}
+
+/// Generates a row of a matrix on a single line, right-aligning each number within its column.
+fn gen_matrix_row<'a>(node: &'a Array<'a>, column_widths: &[usize], context: &mut Context<'a, '_>) -> PrintItems {
+  let mut items = PrintItems::new();
+  items.push_sc(sc!("["));
+  if context.config.array_space_inside {
+    items.push_space();
+  }
+  for (i, (element, width)) in node.elements.iter().zip(column_widths.iter()).enumerate() {
+    if i > 0 {
+      items.push_sc(sc!(", "));
+    }
+    if let Value::NumberLit(number) = element {
+      let padding = width - numbers::format_number(get_number_text(number, context), context.config).len();
+      if padding > 0 {
+        items.push_string(" ".repeat(padding));
+      }
+    }
+    items.extend(gen_node(element.into(), context));
+  }
+  if context.config.array_space_inside {
+    items.push_space();
+  }
+  items.push_sc(sc!("]"));
+  items
+}
+
+/// Generates the property texts of each row when the array is a table, meaning it has at least
+/// two rows that are flat objects with the same keys and without comments. The texts are padded
+/// so the properties are aligned in columns.
+///
+/// The table isn't used when the array would otherwise stay on a single line. Otherwise, the rows
+/// must fit within the line width, which assumes each ancestor is on multiple lines.
+fn get_table_rows<'a>(
+  node: &'a Array<'a>,
+  is_multi_line: bool,
+  context: &mut Context<'a, '_>,
+) -> Option<Vec<Vec<String>>> {
+  if node.elements.len() < 2 || has_element_comments(node, context) {
+    return None;
+  }
+
+  let mut rows: Vec<Vec<String>> = Vec::with_capacity(node.elements.len());
+  let mut names: Option<Vec<&str>> = None;
+  for element in node.elements.iter() {
+    let Value::Object(obj) = element else {
+      return None;
+    };
+    let properties = get_table_row_properties(obj, context)?;
+    let row_names = properties.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+    if names.get_or_insert_with(|| row_names.clone()) != &row_names {
+      return None;
+    }
+
+    let quote_prop_names = get_quote_prop_names(obj, context);
+    let past_quote_prop_names = std::mem::replace(&mut context.quote_prop_names, quote_prop_names);
+    let row = properties
+      .into_iter()
+      .map(|prop| {
+        let items = gen_node(Node::ObjectProp(prop), context);
+        print_single_line(items, context)
+      })
+      .collect::<Vec<_>>();
+    context.quote_prop_names = past_quote_prop_names;
+    // json5 strings may contain line continuations
+    if row.iter().any(|text| text.contains('\n')) {
+      return None;
+    }
+    rows.push(row);
+  }
+
+  let mut column_widths = vec![0; rows[0].len()];
+  for row in rows.iter() {
+    for (width, text) in column_widths.iter_mut().zip(row.iter()) {
+      *width = (*width).max(UnicodeWidthStr::width(text.as_str()));
+    }
+  }
+
+  let depth = context
+    .parent_stack
+    .iter()
+    .filter(|n| matches!(n, Node::Array(_) | Node::Object(_)))
+    .count();
+  let line_width = context.config.line_width as usize;
+  if !is_multi_line {
+    // the brackets and braces along with ", " between each row and property
+    let brackets_width = if context.config.array_space_inside { 4 } else { 2 };
+    let object_braces_width = if context.config.object_space_inside { 4 } else { 2 };
+    let rows_width = rows
+      .iter()
+      .map(|row| object_braces_width + row.iter().map(|t| t.width()).sum::<usize>() + 2 * (row.len() - 1))
+      .sum::<usize>();
+    let single_line_width = brackets_width + rows_width + 2 * (rows.len() - 1);
+    let indent_width = depth * context.config.indent_width as usize;
+    if indent_width + get_prop_prefix_width(context) + single_line_width <= line_width {
+      return None;
+    }
+  }
+
+  // the rows are indented within the array
+  let indent_width = (depth + 1) * context.config.indent_width as usize;
+  let last_index = column_widths.len() - 1;
+  // the braces and trailing comma along with ", " between each property
+  let braces_width = if context.config.object_space_inside { 5 } else { 3 };
+  for row in rows.iter_mut() {
+    let row_width = braces_width
+      + column_widths[..last_index].iter().map(|w| w + 2).sum::<usize>()
+      + UnicodeWidthStr::width(row[last_index].as_str());
+    if indent_width + row_width > line_width {
+      return None;
+    }
+    for (text, width) in row[..last_index].iter_mut().zip(column_widths.iter()) {
+      let padding = width - UnicodeWidthStr::width(text.as_str());
+      text.push(',');
+      text.push_str(&" ".repeat(padding));
+    }
+  }
+  Some(rows)
+}
+
+/// Gets the properties of an object in the order they would be generated when the object may
+/// be a row of a table.
+fn get_table_row_properties<'a>(obj: &'a Object<'a>, context: &Context) -> Option<Vec<&'a ObjectProp<'a>>> {
+  let is_primitive = |value: &Value| {
+    matches!(
+      value,
+      Value::StringLit(_) | Value::NumberLit(_) | Value::BooleanLit(_) | Value::NullKeyword(_)
+    )
+  };
+  if obj.properties.is_empty() || !obj.properties.iter().all(|p| is_primitive(&p.value)) {
+    return None;
+  }
+  // comments are keyed by the positions of the surrounding tokens
+  let has_comments = obj
+    .properties
+    .iter()
+    .flat_map(|p| [p.start(), p.name.end(), p.value.start(), p.end()])
+    .chain([obj.end() - 1])
+    .any(|pos| context.comments.contains_key(&pos));
+  if has_comments {
+    return None;
+  }
+
+  let mut properties = obj.properties.iter().collect::<Vec<_>>();
+  let names = properties.iter().map(|p| p.name.as_str()).collect::<HashSet<_>>();
+  if names.len() != properties.len() {
+    return None;
+  }
+  let sort_keys = context.config.object_sort_keys;
+  if sort_keys != SortKeysKind::Off {
+    properties.sort_by(|a, b| compare_keys(a.name.as_str(), b.name.as_str(), sort_keys));
+  }
+  Some(properties)
+}
+
+/// Prints items that don't contain any newlines to get their text, such as the text of a property.
+fn print_single_line(items: PrintItems, context: &Context) -> String {
+  print(
+    items,
+    PrintOptions {
+      max_width: u32::MAX,
+      indent_width: context.config.indent_width,
+      use_tabs: context.config.use_tabs,
+      new_line_text: "\n",
+    },
+  )
+}
+
+/// Generates a row of a table on a single line.
+fn gen_table_row(row: Vec<String>, context: &Context) -> PrintItems {
+  let mut items = PrintItems::new();
+  items.push_sc(sc!("{"));
+  if context.config.object_space_inside {
+    items.push_space();
+  }
+  items.push_string(row.join(" "));
+  if context.config.object_space_inside {
+    items.push_space();
+  }
+  items.push_sc(sc!("}"));
+  items
+}
+
 fn gen_object<'a>(obj: &'a Object, context: &mut Context<'a, '_>) -> PrintItems {
+  if let Some(row) = context.table_rows.remove(&obj.start()) {
+    return gen_table_row(row, context);
+  }
+
+  let ignored_ranges = get_ignored_ranges(obj.properties.iter().map(|p| p.range), context);
   let force_multi_lines = !context.config.object_prefer_single_line
     && (should_break_up_single_line(obj, context)
+      || has_multi_line_ignored_range(&ignored_ranges, context)
       || context.text_info.line_index(obj.start())
         < obj
           .properties
@@ -167,17 +611,43 @@ fn gen_object<'a>(obj: &'a Object, context: &mut Context<'a, '_>) -> PrintItems
           .map(|p| context.text_info.line_index(p.start()))
           .unwrap_or_else(|| context.text_info.line_index(obj.end())));
 
-  gen_surrounded_by_tokens(
+  let properties = get_kept_properties(obj, &ignored_ranges, context);
+  let first_member = properties.first().map(|p| p.range());
+  let (properties, lines_spans) = match get_sorted_properties(&properties, &ignored_ranges, context) {
+    Some((properties, lines_spans)) => (properties, Some(lines_spans)),
+    None => (properties, None),
+  };
+  let quote_prop_names = get_quote_prop_names(obj, context);
+  let past_quote_prop_names = std::mem::replace(&mut context.quote_prop_names, quote_prop_names);
+
+  let mut items = PrintItems::new();
+  if context.config.object_align_values {
+    let open_brace_line = LineNumber::new("openBraceLine");
+    items.push_info(open_brace_line);
+    align_values(
+      &properties,
+      lines_spans.as_deref(),
+      &ignored_ranges,
+      force_multi_lines,
+      open_brace_line,
+      context,
+    );
+  }
+
+  items.extend(gen_surrounded_by_tokens(
     |context| {
       let mut items = PrintItems::new();
       items.extend(gen_comma_separated_values(
         GenCommaSeparatedValuesOptions {
-          nodes: obj.properties.iter().map(|x| Some(Node::ObjectProp(x))).collect(),
+          nodes: properties.into_iter().map(|x| Some(Node::ObjectProp(x))).collect(),
+          last_value_range: obj.properties.last().map(|x| x.range),
+          lines_spans,
+          ignored_ranges,
           prefer_hanging: false,
           force_use_new_lines: force_multi_lines,
           allow_blank_lines: true,
-          single_line_space_at_start: true,
-          single_line_space_at_end: true,
+          single_line_space_at_start: context.config.object_space_inside,
+          single_line_space_at_end: context.config.object_space_inside,
           custom_single_line_separator: None,
           multi_line_options: ir_helpers::MultiLineOptions::surround_newlines_indented(),
           force_possible_newline_at_start: false,
@@ -190,23 +660,240 @@ fn gen_object<'a>(obj: &'a Object, context: &mut Context<'a, '_>) -> PrintItems
       open_token: sc!("{"),
       close_token: sc!("}"),
       range: obj.range,
-      first_member: obj.properties.first().map(|f| f.range()),
+      first_member,
       prefer_single_line_when_empty: false,
     },
     context,
-  )
+  ));
+
+  context.quote_prop_names = past_quote_prop_names;
+  items
+}
+
+/// Gets whether all the property names of the object should be quoted when `quoteProps` is `consistent`.
+fn get_quote_prop_names(obj: &Object, context: &Context) -> Option<bool> {
+  match context.quote_props() {
+    QuotePropsKind::Consistent => Some(
+      obj
+        .properties
+        .iter()
+        .any(|p| !is_valid_unquoted_prop_name(p.name.as_str())),
+    ),
+    _ => None,
+  }
+}
+
+/// Groups the properties separated by blank lines so that the values of each group start at the
+/// same column. Properties with a name wider than `object.alignValuesMaxNameWidth` aren't aligned.
+fn align_values<'a>(
+  properties: &[&'a ObjectProp<'a>],
+  lines_spans: Option<&[ir_helpers::LinesSpan]>,
+  ignored_ranges: &[IgnoredRange],
+  maintain_blank_lines: bool,
+  open_brace_line: LineNumber,
+  context: &mut Context<'a, '_>,
+) {
+  let max_name_width = context.config.object_align_values_max_name_width as usize;
+  let mut groups = vec![Vec::new()];
+  let mut last_end_line = None;
+  for (i, prop) in properties.iter().copied().enumerate() {
+    let (start_line, end_line) = match lines_spans {
+      Some(lines_spans) => (lines_spans[i].start_line, lines_spans[i].end_line),
+      None => (
+        start_line_with_comments(prop, ignored_ranges, context),
+        context.end_line_with_comments(prop),
+      ),
+    };
+    if maintain_blank_lines && last_end_line.is_some_and(|last_end_line| start_line > last_end_line + 1) {
+      groups.push(Vec::new());
+    }
+    last_end_line = Some(end_line);
+
+    let is_ignored = ignored_ranges
+      .iter()
+      .any(|r| r.range.start <= prop.start() && prop.end() <= r.range.end);
+    if !is_ignored {
+      let name_width = UnicodeWidthStr::width(get_prop_name_text(prop, context).as_str());
+      if name_width <= max_name_width {
+        groups.last_mut().unwrap().push((prop, name_width));
+      }
+    }
+  }
+
+  for group in groups {
+    let max_width = group.iter().map(|(_, width)| *width).max().unwrap_or(0);
+    for (prop, width) in group {
+      if width < max_width {
+        context.aligned_values.insert(
+          prop.start(),
+          ValueAlignment {
+            padding: max_width - width,
+            open_brace_line,
+          },
+        );
+      }
+    }
+  }
+}
+
+/// Gets the text the name of the property will be generated as.
+fn get_prop_name_text<'a>(prop: &'a ObjectProp<'a>, context: &mut Context<'a, '_>) -> String {
+  // the name is only quoted as a property name when its parent is the property
+  context.parent_stack.push(Node::ObjectProp(prop));
+  let items = match &prop.name {
+    ObjectPropName::String(name) => gen_string_lit(name, context),
+    ObjectPropName::Word(name) => gen_word_lit(name, context),
+  };
+  context.parent_stack.pop();
+  print_single_line(items, context)
+}
+
+/// Gets the properties that remain after removing duplicates based on `object.duplicateKeys`.
+///
+/// The comments of a removed property are removed with it. Properties in an ignored range are never removed.
+fn get_kept_properties<'a>(
+  obj: &'a Object<'a>,
+  ignored_ranges: &[IgnoredRange],
+  context: &mut Context<'a, '_>,
+) -> Vec<&'a ObjectProp<'a>> {
+  let removed_duplicates = get_removed_duplicates(obj, context.config.object_duplicate_keys);
+  if removed_duplicates.is_empty() {
+    return obj.properties.iter().collect();
+  }
+
+  let mut properties = Vec::with_capacity(obj.properties.len() - removed_duplicates.len());
+  // the start line of the removed properties since the last kept one, unless a blank line separated them
+  let mut removed_start_line = None;
+  let mut has_removed_blank_line = false;
+  let mut last_end_line = None;
+  for (i, prop) in obj.properties.iter().enumerate() {
+    let is_ignored = ignored_ranges
+      .iter()
+      .any(|r| r.range.start <= prop.start() && prop.end() <= r.range.end);
+    let start_line = start_line_with_comments(prop, ignored_ranges, context);
+    let is_after_blank_line = last_end_line.is_some_and(|end_line| start_line > end_line + 1);
+    last_end_line = Some(context.end_line_with_comments(prop));
+    if removed_duplicates.contains(&i) && !is_ignored {
+      has_removed_blank_line |= is_after_blank_line;
+      removed_start_line.get_or_insert(start_line);
+      mark_removed_prop_comments_handled(prop, ignored_ranges, context);
+    } else {
+      // otherwise the property's own start line maintains the blank line
+      if let Some(removed_start_line) = removed_start_line.take()
+        && !has_removed_blank_line
+        && !is_after_blank_line
+      {
+        context
+          .removed_duplicate_start_lines
+          .insert(prop.start(), removed_start_line);
+      }
+      has_removed_blank_line = false;
+      properties.push(prop);
+    }
+  }
+  properties
+}
+
+fn mark_removed_prop_comments_handled(prop: &ObjectProp, ignored_ranges: &[IgnoredRange], context: &mut Context) {
+  let comments = context.comments;
+  // the leading comments on their own line, except for the end comment of an ignored range
+  let previous_end_line = context
+    .token_finder
+    .get_previous_token(prop)
+    .map(|token| context.text_info.line_index(token.end()));
+  let end_comment_start = ignored_ranges
+    .iter()
+    .find_map(|r| r.end_comment.filter(|(key, _)| *key == prop.start()))
+    .map(|(_, range)| range.start);
+  for comment in comments.get(&prop.start()).into_iter().flat_map(|c| c.iter()) {
+    if previous_end_line.is_none_or(|line| context.text_info.line_index(comment.start()) > line)
+      && end_comment_start.is_none_or(|start| comment.start() > start)
+    {
+      context.mark_comment_handled(comment);
+    }
+  }
+  // the trailing comments on the same line
+  let search_end = context
+    .token_finder
+    .get_next_token_if_comma(prop)
+    .map(|token| token.end())
+    .unwrap_or_else(|| prop.end());
+  let end_line = context.text_info.line_index(search_end);
+  for comment in comments.get(&search_end).into_iter().flat_map(|c| c.iter()) {
+    if context.text_info.line_index(comment.start()) == end_line {
+      context.mark_comment_handled(comment);
+    }
+  }
+}
+
+/// Gets the properties in sorted order along with the lines span to use for each one.
+///
+/// Properties are only sorted within groups separated by blank lines. Each property is given the
+/// lines span of its entire group so that blank lines are only maintained between groups. The
+/// properties of an ignored range form their own group that is never sorted.
+fn get_sorted_properties<'a>(
+  properties: &[&'a ObjectProp<'a>],
+  ignored_ranges: &[IgnoredRange],
+  context: &mut Context<'a, '_>,
+) -> Option<(Vec<&'a ObjectProp<'a>>, Vec<ir_helpers::LinesSpan>)> {
+  let sort_keys = context.config.object_sort_keys;
+  if sort_keys == SortKeysKind::Off || properties.len() < 2 {
+    return None;
+  }
+
+  let mut groups: Vec<(ir_helpers::LinesSpan, Option<usize>, Vec<&'a ObjectProp<'a>>)> = Vec::new();
+  for prop in properties.iter().copied() {
+    let start_line = start_line_with_comments(prop, ignored_ranges, context);
+    let end_line = context.end_line_with_comments(prop);
+    let ignored_index = ignored_ranges
+      .iter()
+      .position(|r| r.range.start <= prop.range.start && prop.range.end <= r.range.end);
+    match groups.last_mut() {
+      Some((lines_span, group_ignored_index, props))
+        if start_line <= lines_span.end_line + 1 && *group_ignored_index == ignored_index =>
+      {
+        lines_span.end_line = end_line;
+        props.push(prop);
+      }
+      _ => groups.push((
+        ir_helpers::LinesSpan { start_line, end_line },
+        ignored_index,
+        vec![prop],
+      )),
+    }
+  }
+
+  let mut properties = Vec::with_capacity(properties.len());
+  let mut lines_spans = Vec::with_capacity(properties.len());
+  for (mut lines_span, ignored_index, mut props) in groups {
+    match ignored_index {
+      Some(index) => ignored_ranges[index].extend_lines_span(&mut lines_span, context),
+      None => props.sort_by(|a, b| compare_keys(a.name.as_str(), b.name.as_str(), sort_keys)),
+    }
+    lines_spans.extend(std::iter::repeat_n(lines_span, props.len()));
+    properties.extend(props);
+  }
+  Some((properties, lines_spans))
 }
 
 fn gen_object_prop<'a>(node: &'a ObjectProp, context: &mut Context<'a, '_>) -> PrintItems {
   let mut items = PrintItems::new();
   items.extend(gen_node((&node.name).into(), context));
+  if context.config.colon_space_before {
+    items.push_space();
+  }
   items.push_sc(sc!(":"));
 
   // Own-line comments between the name and value (the colon isn't a node) would be dropped, so
   // emit them here. Line comments emit their own newline, so only add the space when there were none.
   let dangling_comments = gen_dangling_comments(&[node.name.end(), node.value.start()], context);
   if dangling_comments.is_empty() {
-    items.push_space();
+    if let Some(alignment) = context.aligned_values.remove(&node.start()) {
+      items.extend(gen_value_alignment(alignment));
+    }
+    if context.config.colon_space_after {
+      items.push_space();
+    }
   } else {
     items.extend(dangling_comments);
   }
@@ -216,6 +903,20 @@ fn gen_object_prop<'a>(node: &'a ObjectProp, context: &mut Context<'a, '_>) -> P
   items
 }
 
+fn gen_value_alignment(alignment: ValueAlignment) -> PrintItems {
+  let open_brace_line = alignment.open_brace_line;
+  let mut items = PrintItems::new();
+  items.push_condition(if_true(
+    "alignValue",
+    Rc::new(move |context| {
+      let open_brace_line = context.resolved_line_number(open_brace_line)?;
+      Some(context.writer_info.line_number > open_brace_line)
+    }),
+    " ".repeat(alignment.padding).into(),
+  ));
+  items
+}
+
 // Own-line comments in a slot with no AST node to attach to (e.g. between a property name and its
 // value, or a value and its trailing comma) match no leading/trailing emission path and would be
 // silently dropped. Emit them as statements. `keys` are the comment-map slots to check (jsonc-parser
@@ -254,35 +955,133 @@ fn gen_dangling_comments<'a: 'b, 'b>(keys: &[usize], context: &mut Context<'a, '
   items
 }
 
+/// Gets the text of a number literal including the trailing decimal point json5 allows (ex. `5.`),
+/// which isn't part of the parsed range.
+fn get_number_text<'b>(node: &NumberLit, context: &Context<'_, 'b>) -> &'b str {
+  let text = context.text;
+  if text[node.end()..].starts_with('.') {
+    &text[node.start()..node.end() + 1]
+  } else {
+    node.text(text)
+  }
+}
+
 const DOUBLE_QUOTE_SC: &StringContainer = sc!("\"");
+const SINGLE_QUOTE_SC: &StringContainer = sc!("'");
 
 fn gen_string_lit<'a>(node: &'a StringLit, context: &mut Context<'a, '_>) -> PrintItems {
+  if is_object_prop_name(node, context) && !should_quote_prop_name(&node.value, true, context) {
+    return node.value.to_string().into();
+  }
+
   let text = node.text(context.text);
   let is_double_quotes = text.starts_with('"');
-  let mut items = PrintItems::new();
   let text = &text[1..text.len() - 1];
-  items.push_sc(DOUBLE_QUOTE_SC);
-  if is_double_quotes {
-    items.push_string(text.to_string());
+  let quote = get_string_quote(text, is_double_quotes, context);
+  let mut items = PrintItems::new();
+  items.push_sc(quote);
+  let quote_char = quote.text.chars().next().unwrap();
+  let text = strings::format_string(text, quote_char, context.config);
+  if text.contains('\n') {
+    // json5 strings may contain line continuations, so keep every line
+    // (including a last empty one) and the closing quote as-is without indenting them
+    items.push_signal(Signal::StartIgnoringIndent);
+    for (i, line) in text.split('\n').enumerate() {
+      if i > 0 {
+        items.push_signal(Signal::NewLine);
+      }
+      let line = line.strip_suffix('\r').unwrap_or(line);
+      if !line.is_empty() {
+        items.push_string(line.to_string());
+      }
+    }
+    items.push_sc(quote);
+    items.push_signal(Signal::FinishIgnoringIndent);
   } else {
-    let text = text.replace("\\'", "'");
-    items.push_string(text.replace('"', "\\\""));
+    items.push_string(text.into_owned());
+    items.push_sc(quote);
   }
-  items.push_sc(DOUBLE_QUOTE_SC);
   items
 }
 
-fn gen_word_lit<'a>(node: &'a WordLit<'a>, _: &mut Context<'a, '_>) -> PrintItems {
+fn gen_word_lit<'a>(node: &'a WordLit<'a>, context: &mut Context<'a, '_>) -> PrintItems {
   // this will be a property name that's not a string literal
+  if !should_quote_prop_name(node.value, false, context) {
+    return node.value.to_string().into();
+  }
+
+  let quote = get_string_quote(node.value, true, context);
   let mut items = PrintItems::new();
-  items.push_sc(DOUBLE_QUOTE_SC);
-  items.push_string(node.value.to_string());
-  items.push_sc(DOUBLE_QUOTE_SC);
+  items.push_sc(quote);
+  let quote_char = quote.text.chars().next().unwrap();
+  items.push_string(strings::format_string(node.value, quote_char, context.config).into_owned());
+  items.push_sc(quote);
   items
 }
 
+fn get_string_quote(text: &str, is_double_quotes: bool, context: &Context) -> &'static StringContainer {
+  if !context.is_jsonc() {
+    return DOUBLE_QUOTE_SC;
+  }
+  let use_double_quotes = match context.quote_style() {
+    QuoteStyleKind::AlwaysDouble => true,
+    QuoteStyleKind::AlwaysSingle => false,
+    QuoteStyleKind::PreferDouble => {
+      let (double_count, single_count) = strings::count_quotes(text);
+      double_count <= single_count
+    }
+    QuoteStyleKind::Preserve => is_double_quotes,
+  };
+  if use_double_quotes {
+    DOUBLE_QUOTE_SC
+  } else {
+    SINGLE_QUOTE_SC
+  }
+}
+
+fn is_object_prop_name(node: &dyn Ranged, context: &Context) -> bool {
+  matches!(context.parent_stack.last(), Some(Node::ObjectProp(prop)) if prop.name.start() == node.start())
+}
+
+fn should_quote_prop_name(name: &str, is_quoted: bool, context: &Context) -> bool {
+  if !context.is_jsonc() || (context.config.ensure_ascii && !name.is_ascii()) {
+    return true;
+  }
+  match context.quote_props() {
+    QuotePropsKind::Always => true,
+    QuotePropsKind::AsNeeded => !is_valid_unquoted_prop_name(name),
+    QuotePropsKind::Consistent => context
+      .quote_prop_names
+      .unwrap_or_else(|| !is_valid_unquoted_prop_name(name)),
+    QuotePropsKind::Preserve => is_quoted,
+  }
+}
+
+/// Gets if the property name is an identifier that jsonc-parser will parse as a word.
+fn is_valid_unquoted_prop_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  let is_identifier = match chars.next() {
+    Some(c) if c.is_alphabetic() || c == '_' => chars.all(|c| c.is_alphanumeric() || c == '_'),
+    _ => false,
+  };
+  // words starting with a keyword (ex. `true` or `null_value`) are scanned as the keyword
+  is_identifier
+    && !["true", "false", "null"].iter().any(|keyword| {
+      name
+        .strip_prefix(keyword)
+        .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric()))
+    })
+}
+
 struct GenCommaSeparatedValuesOptions<'a> {
   nodes: Vec<Option<Node<'a, 'a>>>,
+  /// The range of the last value in the file, whose trailing comma is maintained even when the
+  /// values were reordered or it was removed.
+  last_value_range: Option<Range>,
+  /// Lines spans to use instead of the ones computed from the nodes (ex. when the nodes were reordered).
+  lines_spans: Option<Vec<ir_helpers::LinesSpan>>,
+  /// Ranges of consecutive nodes to emit as they are in the source (see `get_ignored_ranges`).
+  ignored_ranges: Vec<IgnoredRange>,
   prefer_hanging: bool,
   force_use_new_lines: bool,
   allow_blank_lines: bool,
@@ -300,36 +1099,66 @@ fn gen_comma_separated_values<'a>(
   let nodes = opts.nodes;
   let indent_width = context.config.indent_width;
   let compute_lines_span = opts.allow_blank_lines && opts.force_use_new_lines; // save time otherwise
+  let mut lines_spans = opts.lines_spans.map(|spans| spans.into_iter());
+  let last_value_range = opts.last_value_range;
+  if context.config.comment_align_trailing {
+    align_trailing_comments(&nodes, &opts.ignored_ranges, context);
+  }
   ir_helpers::gen_separated_values(
     |is_multi_line_or_hanging_ref| {
       let mut generated_nodes = Vec::new();
-      let nodes_count = nodes.len();
-      for (i, value) in nodes.into_iter().enumerate() {
+      let mut nodes = nodes.into_iter().peekable();
+      while let Some(value) = nodes.next() {
         let (allow_inline_multi_line, allow_inline_single_line) = if let Some(value) = &value {
           (value.kind() == NodeKind::Object, false)
         } else {
           (false, false)
         };
-        let lines_span = if compute_lines_span {
-          value.as_ref().map(|x| ir_helpers::LinesSpan {
-            start_line: context.start_line_with_comments(x),
-            end_line: context.end_line_with_comments(x),
-          })
+        let mut lines_span = if compute_lines_span {
+          match &mut lines_spans {
+            Some(lines_spans) => lines_spans.next(),
+            None => value.as_ref().map(|x| ir_helpers::LinesSpan {
+              start_line: start_line_with_comments(x, &opts.ignored_ranges, context),
+              end_line: context.end_line_with_comments(x),
+            }),
+          }
         } else {
           None
         };
+        // collect the rest of the nodes in an ignored range
+        let ignored_range = value
+          .as_ref()
+          .and_then(|value| opts.ignored_ranges.iter().find(|r| r.range.start == value.start()));
+        let mut ignored_last_node = None;
+        if let Some(ignored_range) = ignored_range {
+          let range_end = ignored_range.range.end;
+          while let Some(next) = nodes.next_if(|n| n.as_ref().is_some_and(|n| n.end() <= range_end)) {
+            let next = next.unwrap();
+            if compute_lines_span {
+              let next_lines_span = match &mut lines_spans {
+                Some(lines_spans) => lines_spans.next(),
+                None => Some(ir_helpers::LinesSpan {
+                  start_line: context.start_line_with_comments(&next),
+                  end_line: context.end_line_with_comments(&next),
+                }),
+              };
+              if let (Some(lines_span), Some(next_lines_span)) = (&mut lines_span, next_lines_span) {
+                lines_span.start_line = std::cmp::min(lines_span.start_line, next_lines_span.start_line);
+                lines_span.end_line = std::cmp::max(lines_span.end_line, next_lines_span.end_line);
+              }
+            }
+            ignored_last_node = Some(next);
+          }
+          // the lines spans of sorted properties already include the end comment
+          if lines_spans.is_none()
+            && let Some(lines_span) = &mut lines_span
+          {
+            ignored_range.extend_lines_span(lines_span, context);
+          }
+        }
         let items = ir_helpers::new_line_group({
-          let is_final_node = i == nodes_count - 1;
-          let use_comma_for_last = !is_final_node
-            || match context.config.trailing_commas {
-              TrailingCommaKind::Always => true,
-              TrailingCommaKind::Maintain => match &value {
-                Some(value) => context.token_finder.get_next_token_if_comma(&value.range()).is_some(),
-                None => false,
-              },
-              TrailingCommaKind::Jsonc => context.is_jsonc,
-              TrailingCommaKind::Never => false,
-            };
+          let is_final_node = nodes.peek().is_none();
+          let use_comma_for_last = !is_final_node || should_use_trailing_comma(last_value_range, context);
           let maybe_comma = if !is_final_node {
             ",".into()
           } else if use_comma_for_last {
@@ -338,12 +1167,18 @@ fn gen_comma_separated_values<'a>(
           } else {
             PrintItems::new()
           };
-          gen_comma_separated_value(value, maybe_comma, context)
+          match (value, ignored_range) {
+            (Some(first_node), Some(ignored_range)) => {
+              let last_node = ignored_last_node.unwrap_or(first_node);
+              gen_ignored_range(ignored_range, last_node, maybe_comma, context)
+            }
+            (value, _) => gen_comma_separated_value(value, maybe_comma, context),
+          }
         });
         generated_nodes.push(ir_helpers::GeneratedValue {
           items,
           lines_span,
-          allow_inline_multi_line,
+          allow_inline_multi_line: allow_inline_multi_line && ignored_range.is_none(),
           allow_inline_single_line,
         });
       }
@@ -369,6 +1204,19 @@ fn gen_comma_separated_values<'a>(
   .items
 }
 
+/// Gets if a comma should follow the last value when the values are multi-line.
+fn should_use_trailing_comma(last_value_range: Option<Range>, context: &mut Context) -> bool {
+  match context.config.trailing_commas {
+    TrailingCommaKind::Always => true,
+    TrailingCommaKind::Maintain => match &last_value_range {
+      Some(range) => context.token_finder.get_next_token_if_comma(range).is_some(),
+      None => false,
+    },
+    TrailingCommaKind::Jsonc => context.is_jsonc() || context.is_special_json_file,
+    TrailingCommaKind::Never => false,
+  }
+}
+
 fn gen_comma_separated_value<'a>(
   value: Option<Node<'a, 'a>>,
   generated_comma: PrintItems,
@@ -411,6 +1259,144 @@ fn gen_comma_separated_value<'a>(
   }
 }
 
+/// Groups the trailing line comments of consecutive values so they get aligned to the same column.
+/// A value without a trailing line comment or a blank line ends a group.
+fn align_trailing_comments(nodes: &[Option<Node>], ignored_ranges: &[IgnoredRange], context: &mut Context) {
+  let mut group = Vec::new();
+  let mut last_end_line = None;
+  for node in nodes.iter().flatten() {
+    let is_ignored = ignored_ranges
+      .iter()
+      .any(|r| r.range.start <= node.start() && node.end() <= r.range.end);
+    let comment_start = if is_ignored {
+      None
+    } else {
+      get_trailing_line_comment_start(node, context)
+    };
+    match comment_start {
+      Some(comment_start) => {
+        let start_line = context.start_line_with_comments(node);
+        if last_end_line.is_some_and(|end_line| start_line > end_line + 1) {
+          finish_group(&mut group, context);
+        }
+        group.push(comment_start);
+        last_end_line = Some(context.end_line_with_comments(node));
+      }
+      None => {
+        finish_group(&mut group, context);
+        last_end_line = None;
+      }
+    }
+  }
+  finish_group(&mut group, context);
+
+  fn finish_group(group: &mut Vec<usize>, context: &mut Context) {
+    if group.len() > 1 {
+      let columns = group
+        .iter()
+        .map(|_| ColumnNumber::new("trailingCommentColumn"))
+        .collect::<Vec<_>>();
+      let group_columns = Rc::new(columns.clone());
+      for (comment_start, column) in group.iter().zip(columns) {
+        context.aligned_trailing_comments.insert(
+          *comment_start,
+          TrailingCommentAlignment {
+            column,
+            group_columns: group_columns.clone(),
+          },
+        );
+      }
+    }
+    group.clear();
+  }
+
+  fn get_trailing_line_comment_start(node: &Node, context: &mut Context) -> Option<usize> {
+    let search_end = context
+      .token_finder
+      .get_next_token_if_comma(node)
+      .map(|token| token.end())
+      .unwrap_or_else(|| node.end());
+    let end_line = context.text_info.line_index(search_end);
+    let comment = context.comments.get(&search_end)?.iter().next()?;
+    if comment.kind() == CommentKind::Line && context.text_info.line_index(comment.start()) == end_line {
+      Some(comment.start())
+    } else {
+      None
+    }
+  }
+}
+
+/// Pads a trailing line comment up to the furthest column of the comments in its group.
+fn gen_trailing_comment_alignment(alignment: &TrailingCommentAlignment) -> PrintItems {
+  let mut items = PrintItems::new();
+  items.push_info(alignment.column);
+  // pad using decreasing powers of two to avoid a condition per space
+  for width in [64, 32, 16, 8, 4, 2, 1] {
+    let group_columns = alignment.group_columns.clone();
+    items.push_condition(if_true(
+      "alignTrailingComment",
+      Rc::new(move |context| {
+        let mut max_column = 0;
+        let mut is_resolved = true;
+        // resolve every column so that they're all looked ahead at once
+        for column in group_columns.iter() {
+          match context.resolved_column_number(*column) {
+            Some(column) => max_column = std::cmp::max(max_column, column),
+            None => is_resolved = false,
+          }
+        }
+        is_resolved.then(|| context.writer_info.column_number + width <= max_column)
+      }),
+      " ".repeat(width as usize).into(),
+    ));
+  }
+  items
+}
+
+/// Generates the nodes of an ignored range as they are in the source, including the commas between them.
+fn gen_ignored_range<'a>(
+  ignored_range: &IgnoredRange,
+  last_node: Node<'a, 'a>,
+  generated_comma: PrintItems,
+  context: &mut Context<'a, '_>,
+) -> PrintItems {
+  let range = ignored_range.range;
+  let mut items = PrintItems::new();
+  if let Some(comments) = context.comments.get(&range.start) {
+    items.extend(gen_comments_as_leading(&range, comments.iter(), context));
+  }
+  items.push_force_current_line_indentation();
+  items.extend(ir_helpers::gen_from_raw_string(&context.text[range.start..range.end]));
+
+  let comma_token = context.token_finder.get_next_token_if_comma(&last_node);
+  if comma_token.is_some() {
+    items.extend(gen_dangling_comments(&[range.end], context));
+  }
+  items.extend(generated_comma);
+  if let Some(comments) = context.comments.get(&range.end) {
+    items.extend(gen_comments_as_trailing(&last_node, comments.iter(), context));
+  }
+  if let Some(comma_token) = comma_token {
+    items.extend(gen_trailing_comments(comma_token, context));
+  }
+
+  // keep the end comment with the range because the node it's a leading comment of may be moved when sorting
+  if let Some((key, end_comment_range)) = ignored_range.end_comment
+    && let Some(comments) = context.comments.get(&key)
+  {
+    let comments = comments
+      .iter()
+      .filter(|c| c.start() <= end_comment_range.start && !is_comma_trailing_comment(c, context))
+      .collect::<Vec<_>>();
+    items.extend(gen_comments_as_statements(
+      comments.into_iter(),
+      Some(&last_node),
+      context,
+    ));
+  }
+  items
+}
+
 struct GenSurroundedByTokensOptions {
   open_token: &'static StringContainer,
   close_token: &'static StringContainer,
@@ -544,6 +1530,8 @@ fn gen_surrounded_by_tokens<'a, 'b>(
       }
     } else if !is_single_line && !opts.prefer_single_line_when_empty {
       items.push_signal(Signal::NewLine);
+    } else if context.config.empty_container_space_inside {
+      items.push_space();
     }
   }
 
@@ -639,7 +1627,9 @@ fn gen_comments_as_leading<'a: 'b, 'b>(
   context: &mut Context,
 ) -> PrintItems {
   let mut items = PrintItems::new();
-  let comments = comments.filter(|c| !context.has_handled_comment(c)).collect::<Vec<_>>();
+  let comments = comments
+    .filter(|c| !context.has_handled_comment(c) && !is_comma_trailing_comment(c, context))
+    .collect::<Vec<_>>();
 
   if !comments.is_empty() {
     let last_comment = comments.last().unwrap();
@@ -662,6 +1652,19 @@ fn gen_comments_as_leading<'a: 'b, 'b>(
   items
 }
 
+// Comments on the same line as a preceding comma are the trailing comments of the value before the
+// comma. They're usually handled by the time the next value is generated, but not when values are
+// reordered, so they must not be pulled in as leading comments of the next value.
+fn is_comma_trailing_comment(comment: &Comment, context: &mut Context) -> bool {
+  match context.token_finder.get_previous_token(comment) {
+    Some(token) => {
+      token.token == Token::Comma
+        && context.text_info.line_index(token.end()) == context.text_info.line_index(comment.start())
+    }
+    None => false,
+  }
+}
+
 fn gen_comments_as_trailing<'a: 'b, 'b>(
   node: &dyn Ranged,
   comments: impl Iterator<Item = &'b Comment<'a>>,
@@ -738,7 +1741,11 @@ fn gen_comment_based_on_last_node(
 
   if let Some(last_node) = last_node {
     let comment_start_line = context.text_info.line_index(comment.start());
-    let last_node_end_line = context.text_info.line_index(last_node.end());
+    let last_node_end = match context.joined_comment_ends.get(&last_node.start()) {
+      Some(end) => *end,
+      None => last_node.end(),
+    };
+    let last_node_end_line = context.text_info.line_index(last_node_end);
 
     if opts.separate_with_newlines || comment_start_line > last_node_end_line {
       items.push_signal(Signal::NewLine);
@@ -748,6 +1755,9 @@ fn gen_comment_based_on_last_node(
       }
     } else if comment.kind() == CommentKind::Line {
       items.push_signal(Signal::StartForceNoNewLines);
+      if let Some(alignment) = context.aligned_trailing_comments.get(&comment.start()) {
+        items.extend(gen_trailing_comment_alignment(alignment));
+      }
       items.push_space();
       pushed_ignore_new_lines = true;
     } else if last_node.text(context.text).starts_with("/*") {
@@ -775,21 +1785,330 @@ fn gen_comment(comment: &Comment, context: &mut Context) -> Option<PrintItems> {
   // mark handled and generate
   context.mark_comment_handled(comment);
   Some(match comment {
-    Comment::Block(comment) => ir_helpers::gen_js_like_comment_block(comment.text),
-    Comment::Line(comment) => {
-      ir_helpers::gen_js_like_comment_line(comment.text, context.config.comment_line_force_space_after_slashes)
+    Comment::Block(block) => {
+      // only a block comment followed by a newline can become a line comment without consuming what follows
+      let line_text = match context.config.comment_style {
+        CommentStyleKind::Line if is_followed_by_new_line(comment, context) => comments::block_to_line_text(block.text),
+        _ => None,
+      };
+      match line_text {
+        Some(line_text) => gen_comment_line(&line_text, comment, context),
+        None => gen_comment_block(block.text, comment, context),
+      }
+    }
+    Comment::Line(line) => {
+      let block_text = match context.config.comment_style {
+        CommentStyleKind::Block => comments::line_to_block_text(line.text),
+        _ => None,
+      };
+      match block_text {
+        Some(block_text) => gen_comment_block(&block_text, comment, context),
+        None => gen_comment_line(line.text, comment, context),
+      }
     }
   })
 }
 
+fn gen_comment_line(text: &str, comment: &Comment, context: &mut Context) -> PrintItems {
+  let force_space_after_slashes = context.config.comment_line_force_space_after_slashes;
+  let mut words = comments::split_words(text);
+  if !is_prose_comment_line(text, context) || words.is_empty() || !should_wrap_comment(comment, context) {
+    return ir_helpers::gen_js_like_comment_line(text, force_space_after_slashes);
+  }
+
+  // keep the indentation after the space (ex. for the continued lines of a list item)
+  let content = text.strip_prefix(' ').unwrap_or(text);
+  let indent = &content[..content.len() - content.trim_start().len()];
+  let hanging_indent = comments::get_list_marker_width(content.trim_start()).unwrap_or(0);
+  if let Comment::Line(_) = comment {
+    let continuation_indent = format!("{}{}", indent, " ".repeat(hanging_indent));
+    for next_comment in get_paragraph_line_comments(comment, &continuation_indent, context) {
+      let mut next_words = comments::split_words(next_comment.text);
+      // the line break becomes a space that may be wrapped at
+      next_words[0].whitespace_before = " ";
+      words.extend(next_words);
+      context.mark_comment_handled(&Comment::Line(next_comment.clone()));
+      context
+        .joined_comment_ends
+        .insert(comment.start(), next_comment.range.end);
+    }
+  }
+
+  let mut items = PrintItems::new();
+  items.extend(ir_helpers::gen_from_string(&format!("// {}", indent)));
+  items.extend(gen_wrapped_words(
+    &words,
+    &format!("// {}{}", indent, " ".repeat(hanging_indent)),
+    context,
+  ));
+  items.push_signal(Signal::ExpectNewLine);
+  items
+}
+
+/// Gets if the text of a line comment is prose that may be wrapped (ex. not `///` or `//---`).
+fn is_prose_comment_line(text: &str, context: &Context) -> bool {
+  (text.starts_with(' ') || context.config.comment_line_force_space_after_slashes) && !text.starts_with('/')
+}
+
+/// Gets the line comments on the lines directly after the comment that continue its paragraph,
+/// which are joined with it before it's wrapped.
+///
+/// A paragraph ends at an empty comment, a list item, a change in indentation or an ignore comment.
+fn get_paragraph_line_comments<'b>(
+  comment: &Comment,
+  continuation_indent: &str,
+  context: &Context<'_, 'b>,
+) -> Vec<CommentLine<'b>> {
+  let text = context.text;
+  let mut next_comments = Vec::new();
+  let mut pos = comment.end();
+  loop {
+    let line_start = if text[pos..].starts_with('\n') {
+      pos + 1
+    } else if text[pos..].starts_with("\r\n") {
+      pos + 2
+    } else {
+      break;
+    };
+    let line_end = text[line_start..]
+      .find('\n')
+      .map(|i| line_start + i)
+      .unwrap_or(text.len());
+    let line_text = text[line_start..line_end].trim_end_matches('\r');
+    let comment_start = line_start + line_text.len() - line_text.trim_start_matches([' ', '\t']).len();
+    let comment_end = line_start + line_text.len();
+    let Some(comment_text) = text[comment_start..comment_end].strip_prefix("//") else {
+      break;
+    };
+    let next_comment = CommentLine {
+      range: Range::new(comment_start, comment_end),
+      text: comment_text,
+    };
+    let content = comment_text.strip_prefix(' ').unwrap_or(comment_text);
+    if !is_prose_comment_line(comment_text, context)
+      || content.trim().is_empty()
+      || content
+        .strip_prefix(continuation_indent)
+        .is_none_or(|text| text.starts_with(char::is_whitespace))
+      || comments::get_list_marker_width(content.trim_start()).is_some()
+      || has_ignore_comment_text(&Comment::Line(next_comment.clone()), "", context)
+    {
+      break;
+    }
+    pos = comment_end;
+    next_comments.push(next_comment);
+  }
+  next_comments
+}
+
+fn gen_comment_block(text: &str, comment: &Comment, context: &Context) -> PrintItems {
+  if should_wrap_comment(comment, context)
+    && let Some(lines) = comments::parse_js_doc_lines(text)
+  {
+    return gen_js_doc_comment(lines, context);
+  }
+
+  let text = if context.config.comment_block_space_inside {
+    comments::with_space_inside(text)
+  } else {
+    Cow::Borrowed(text)
+  };
+  if !text.contains('\n') || context.config.comment_block_preserve_indent {
+    return ir_helpers::gen_js_like_comment_block(&text);
+  }
+
+  // re-indent the lines relative to the indentation of the line the comment starts on
+  let indent_width = context.config.indent_width;
+  let base_indent_width = comments::get_line_indent_width(context.text, comment.start(), indent_width);
+  let mut items = PrintItems::new();
+  items.push_sc(sc!("/*"));
+  let mut lines = text.split('\n').peekable();
+  let mut is_first_line = true;
+  while let Some(line) = lines.next() {
+    let line = if is_first_line {
+      line
+    } else {
+      items.push_signal(Signal::NewLine);
+      comments::strip_indent(line, base_indent_width, indent_width)
+    };
+    is_first_line = false;
+    // the whitespace on the last line is before the `*/`
+    let line = if lines.peek().is_some() { line.trim_end() } else { line };
+    items.extend(ir_helpers::gen_from_string(line));
+  }
+  items.push_sc(sc!("*/"));
+  items
+}
+
+fn gen_js_doc_comment(lines: Vec<comments::JsDocLine>, context: &Context) -> PrintItems {
+  let mut items = PrintItems::new();
+  items.push_sc(sc!("/**"));
+  for line in lines {
+    items.push_signal(Signal::NewLine);
+    match line {
+      comments::JsDocLine::Verbatim("") => items.push_sc(sc!(" *")),
+      comments::JsDocLine::Verbatim(text) => {
+        items.push_sc(sc!(" * "));
+        items.extend(ir_helpers::gen_from_string(text));
+      }
+      comments::JsDocLine::Paragraph {
+        indent,
+        hanging_indent,
+        words,
+      } => {
+        items.push_string(format!(" * {}", " ".repeat(indent)));
+        items.extend(gen_wrapped_words(
+          &words,
+          &format!(" * {}", " ".repeat(hanging_indent)),
+          context,
+        ));
+      }
+    }
+  }
+  items.push_signal(Signal::NewLine);
+  items.push_sc(sc!(" */"));
+  items
+}
+
+/// Generates the words separated by their original whitespace, which becomes a newline
+/// followed by the prefix when the next word would exceed the comment line width.
+fn gen_wrapped_words(words: &[comments::CommentWord], continuation_prefix: &str, context: &Context) -> PrintItems {
+  let line_width = context.config.comment_line_width.unwrap_or(context.config.line_width);
+  let mut items = PrintItems::new();
+  for (i, word) in words.iter().enumerate() {
+    if i > 0 {
+      let width = (word.whitespace_before.width() + word.text.width()) as u32;
+      let mut new_line_items = PrintItems::new();
+      new_line_items.push_signal(Signal::NewLine);
+      new_line_items.push_string(continuation_prefix.to_string());
+      items.push_condition(if_true_or(
+        "wrapCommentWord",
+        Rc::new(move |context| Some(context.writer_info.column_number + width > line_width)),
+        new_line_items,
+        ir_helpers::gen_from_string(word.whitespace_before),
+      ));
+    }
+    items.extend(ir_helpers::gen_from_string(word.text));
+  }
+  items
+}
+
+/// Gets if the comment should be re-wrapped, which is only done for comments on their own line.
+fn should_wrap_comment(comment: &Comment, context: &Context) -> bool {
+  if !context.config.comment_wrap || has_ignore_comment_text(comment, "", context) {
+    return false;
+  }
+  let line_start = context.text[..comment.start()].rfind('\n').map(|i| i + 1).unwrap_or(0);
+  context.text[line_start..comment.start()].trim().is_empty()
+}
+
+fn is_followed_by_new_line(comment: &Comment, context: &Context) -> bool {
+  let text_after = &context.text[comment.end()..];
+  let line_end = text_after.find('\n').unwrap_or(text_after.len());
+  text_after[..line_end].trim().is_empty()
+}
+
 fn has_ignore_comment(node: &dyn Ranged, context: &Context) -> bool {
   if let Some(last_comment) = context.comments.get(&(node.start())).and_then(|c| c.last()) {
-    ir_helpers::text_has_dprint_ignore(last_comment.text(), &context.config.ignore_node_comment_text)
+    has_ignore_comment_text(last_comment, "", context)
+      && !["-start", "-end", "-file"]
+        .iter()
+        .any(|suffix| has_ignore_comment_text(last_comment, suffix, context))
   } else {
     false
   }
 }
 
+/// Gets if the comment has one of the ignore comment texts followed by the suffix (ex. `-start` for `dprint-ignore-start`).
+fn has_ignore_comment_text(comment: &Comment, suffix: &str, context: &Context) -> bool {
+  context.config.ignore_node_comment_text.iter().any(|text| {
+    let ignore_text = format!("{}{}", text, suffix);
+    ir_helpers::text_has_dprint_ignore(comment.text(), &ignore_text)
+  })
+}
+
+/// A run of consecutive nodes in an object or array to emit as they are in the source.
+struct IgnoredRange {
+  /// The range from the start of the first node to the end of the last node.
+  range: Range,
+  /// The start of the node after the range and the range of the ignore end comment in its leading comments.
+  end_comment: Option<(usize, Range)>,
+}
+
+impl IgnoredRange {
+  fn extend_lines_span(&self, lines_span: &mut ir_helpers::LinesSpan, context: &Context) {
+    if let Some((_, end_comment_range)) = self.end_comment {
+      lines_span.end_line = std::cmp::max(lines_span.end_line, context.text_info.line_index(end_comment_range.end));
+    }
+  }
+}
+
+/// Gets the runs of nodes that start with an ignore start comment (ex. `dprint-ignore-start`) and
+/// continue until a node with an ignore end comment (ex. `dprint-ignore-end`) or the last node.
+fn get_ignored_ranges(node_ranges: impl Iterator<Item = Range>, context: &Context) -> Vec<IgnoredRange> {
+  let find_leading_comment = |range: &Range, suffix: &str| {
+    context
+      .comments
+      .get(&range.start)
+      .and_then(|comments| comments.iter().find(|c| has_ignore_comment_text(c, suffix, context)))
+  };
+  let mut ignored_ranges = Vec::new();
+  let mut current_range: Option<IgnoredRange> = None;
+  for range in node_ranges {
+    if let Some(current) = &mut current_range {
+      match find_leading_comment(&range, "-end") {
+        Some(end_comment) => {
+          current.end_comment = Some((range.start, end_comment.range()));
+          ignored_ranges.extend(current_range.take());
+        }
+        None => {
+          current.range.end = range.end;
+          continue;
+        }
+      }
+    }
+    if find_leading_comment(&range, "-start").is_some() {
+      current_range = Some(IgnoredRange {
+        range,
+        end_comment: None,
+      });
+    }
+  }
+  ignored_ranges.extend(current_range);
+  ignored_ranges
+}
+
+/// Gets the start line of the node including its leading comments, but excluding the
+/// leading comments up to an ignore end comment since they're part of the ignored range.
+fn start_line_with_comments(node: &dyn Ranged, ignored_ranges: &[IgnoredRange], context: &mut Context) -> usize {
+  let end_comment_range = ignored_ranges
+    .iter()
+    .find_map(|r| r.end_comment.filter(|(key, _)| *key == node.start()))
+    .map(|(_, range)| range);
+  let start_line = match end_comment_range {
+    Some(end_comment_range) => {
+      let start = context
+        .comments
+        .get(&node.start())
+        .and_then(|comments| comments.iter().find(|c| c.start() > end_comment_range.start))
+        .map(|c| c.start())
+        .unwrap_or(node.start());
+      context.text_info.line_index(start)
+    }
+    None => context.start_line_with_comments(node),
+  };
+  match context.removed_duplicate_start_lines.get(&node.start()) {
+    Some(removed_start_line) => std::cmp::min(*removed_start_line, start_line),
+    None => start_line,
+  }
+}
+
+fn has_multi_line_ignored_range(ignored_ranges: &[IgnoredRange], context: &Context) -> bool {
+  ignored_ranges
+    .iter()
+    .any(|r| context.text_info.line_index(r.range.start) < context.text_info.line_index(r.range.end))
+}
+
 fn should_break_up_single_line(ranged: &impl Ranged, context: &Context) -> bool {
   // This is a massive performance improvement when formatting huge single line files.
   // Basically, if the node is on a single line and will for sure format as multi-line, then
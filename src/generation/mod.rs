@@ -1,5 +1,15 @@
+mod comments;
 mod context;
+mod duplicate_keys;
 mod generate;
+mod numbers;
+mod range;
+mod sort_keys;
+mod strings;
 mod token_finder;
 
+pub use context::FileKind;
+pub use duplicate_keys::find_duplicate_keys;
 pub use generate::generate;
+pub use generate::generate_node;
+pub use range::get_range_node;
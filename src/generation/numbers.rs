@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+
+use crate::configuration::Configuration;
+use crate::configuration::ExponentCaseKind;
+
+/// Normalizes the text of a number literal based on the `number.*` options.
+///
+/// This is purely textual and never parses the number to avoid losing precision.
+pub fn format_number<'a>(text: &'a str, config: &Configuration) -> Cow<'a, str> {
+  let unsigned_text = text.trim_start_matches(['+', '-']);
+  let is_hex = unsigned_text.starts_with("0x") || unsigned_text.starts_with("0X");
+  if is_hex || !has_number_options(config) {
+    return Cow::Borrowed(text);
+  }
+
+  let (sign, rest) = match text.strip_prefix(['+', '-']) {
+    Some(rest) => text.split_at(text.len() - rest.len()),
+    None => ("", text),
+  };
+  let (mantissa, exponent) = match rest.find(['e', 'E']) {
+    Some(index) => (&rest[..index], Some((&rest[index..index + 1], &rest[index + 1..]))),
+    None => (rest, None),
+  };
+  let (integer, fraction) = match mantissa.split_once('.') {
+    Some((integer, fraction)) => (integer, Some(fraction)),
+    None => (mantissa, None),
+  };
+
+  let mut result = String::with_capacity(text.len());
+  let is_zero = integer.chars().chain(fraction.unwrap_or("").chars()).all(|c| c == '0');
+  if !(sign == "-" && is_zero && config.number_normalize_negative_zero) {
+    result.push_str(sign);
+  }
+  result.push_str(integer);
+  if let Some(fraction) = fraction {
+    let fraction = if config.number_remove_trailing_fraction_zeros {
+      fraction.trim_end_matches('0')
+    } else {
+      fraction
+    };
+    if !fraction.is_empty() || !config.number_remove_trailing_fraction_zeros {
+      result.push('.');
+      result.push_str(fraction);
+    } else if integer.is_empty() {
+      // json5 allows a leading decimal point (ex. `.0`)
+      result.push('0');
+    }
+  }
+  if let Some((marker, exponent)) = exponent {
+    result.push_str(match config.number_exponent_case {
+      ExponentCaseKind::Maintain => marker,
+      ExponentCaseKind::Lowercase => "e",
+      ExponentCaseKind::Uppercase => "E",
+    });
+    let (exponent_sign, exponent_digits) = match exponent.strip_prefix(['+', '-']) {
+      Some(digits) => exponent.split_at(exponent.len() - digits.len()),
+      None => ("", exponent),
+    };
+    if !(exponent_sign == "+" && config.number_remove_exponent_plus_sign) {
+      result.push_str(exponent_sign);
+    }
+    if config.number_remove_exponent_leading_zeros {
+      let digits = exponent_digits.trim_start_matches('0');
+      result.push_str(if digits.is_empty() { "0" } else { digits });
+    } else {
+      result.push_str(exponent_digits);
+    }
+  }
+
+  if result == text {
+    Cow::Borrowed(text)
+  } else {
+    Cow::Owned(result)
+  }
+}
+
+fn has_number_options(config: &Configuration) -> bool {
+  config.number_exponent_case != ExponentCaseKind::Maintain
+    || config.number_remove_exponent_plus_sign
+    || config.number_remove_trailing_fraction_zeros
+    || config.number_remove_exponent_leading_zeros
+    || config.number_normalize_negative_zero
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::configuration::ConfigurationBuilder;
+
+  use super::*;
+
+  #[test]
+  fn formats_numbers() {
+    let config = ConfigurationBuilder::new()
+      .number_exponent_case(ExponentCaseKind::Lowercase)
+      .number_remove_exponent_plus_sign(true)
+      .number_remove_trailing_fraction_zeros(true)
+      .number_remove_exponent_leading_zeros(true)
+      .number_normalize_negative_zero(true)
+      .build();
+    assert_eq!(format_number("1", &config), "1");
+    assert_eq!(format_number("1.50", &config), "1.5");
+    assert_eq!(format_number("1.00", &config), "1");
+    assert_eq!(format_number("1.", &config), "1");
+    assert_eq!(format_number(".00", &config), "0");
+    assert_eq!(format_number("-0", &config), "0");
+    assert_eq!(format_number("-0.000", &config), "0");
+    assert_eq!(format_number("-0.001", &config), "-0.001");
+    assert_eq!(format_number("1E+05", &config), "1e5");
+    assert_eq!(format_number("1e-005", &config), "1e-5");
+    assert_eq!(format_number("1e000", &config), "1e0");
+    assert_eq!(format_number("+1.10E+2", &config), "+1.1e2");
+    assert_eq!(format_number("0xFE", &config), "0xFE");
+    assert_eq!(format_number("-0X0", &config), "-0X0");
+  }
+
+  #[test]
+  fn formats_uppercase_exponent() {
+    let config = ConfigurationBuilder::new()
+      .number_exponent_case(ExponentCaseKind::Uppercase)
+      .build();
+    assert_eq!(format_number("1e+05", &config), "1E+05");
+    assert_eq!(format_number("1.0", &config), "1.0");
+    assert_eq!(format_number("1.", &config), "1.");
+  }
+}
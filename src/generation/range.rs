@@ -0,0 +1,48 @@
+use jsonc_parser::ast::*;
+use jsonc_parser::common::Range;
+use jsonc_parser::common::Ranged;
+
+/// Gets the smallest object, array, or object property that contains the provided range.
+///
+/// Returns the root value when the range only partially intersects it and `None` when
+/// the range is outside the root value or the root value is not an object or array.
+pub fn get_range_node<'a, 'b>(value: &'b Value<'a>, range: Range) -> Option<Node<'a, 'b>> {
+  if !matches!(value, Value::Object(_) | Value::Array(_)) || range.start > value.end() || range.end < value.start() {
+    return None;
+  }
+
+  let mut node: Node<'a, 'b> = value.into();
+  let mut container = Some(value);
+  while let Some(value) = container.take() {
+    match value {
+      Value::Object(obj) => {
+        if let Some(prop) = obj.properties.iter().find(|p| contains(*p, range)) {
+          node = Node::ObjectProp(prop);
+          container = get_container(&prop.value, range);
+          if let Some(value) = container {
+            node = value.into();
+          }
+        }
+      }
+      Value::Array(array) => {
+        if let Some(value) = array.elements.iter().find_map(|e| get_container(e, range)) {
+          node = value.into();
+          container = Some(value);
+        }
+      }
+      _ => {}
+    }
+  }
+  Some(node)
+}
+
+fn get_container<'a, 'b>(value: &'b Value<'a>, range: Range) -> Option<&'b Value<'a>> {
+  match value {
+    Value::Object(_) | Value::Array(_) if contains(value, range) => Some(value),
+    _ => None,
+  }
+}
+
+fn contains(node: &dyn Ranged, range: Range) -> bool {
+  node.start() <= range.start && range.end <= node.end()
+}
@@ -0,0 +1,84 @@
+use std::cmp::Ordering;
+
+use crate::configuration::SortKeysKind;
+
+/// Compares two decoded property names according to the provided sort kind.
+pub fn compare_keys(a: &str, b: &str, kind: SortKeysKind) -> Ordering {
+  match kind {
+    SortKeysKind::Off => Ordering::Equal,
+    SortKeysKind::Ascending => a.cmp(b),
+    SortKeysKind::Descending => b.cmp(a),
+    SortKeysKind::Natural => compare_natural(a, b),
+    SortKeysKind::CaseInsensitive => a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)),
+  }
+}
+
+fn compare_natural(a: &str, b: &str) -> Ordering {
+  let mut a_chunks = Chunks(a);
+  let mut b_chunks = Chunks(b);
+  loop {
+    let ordering = match (a_chunks.next(), b_chunks.next()) {
+      (None, None) => return a.cmp(b),
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(a_chunk), Some(b_chunk)) => {
+        if is_digits(a_chunk) && is_digits(b_chunk) {
+          // compare textually so that numbers of any length are supported
+          let a_digits = a_chunk.trim_start_matches('0');
+          let b_digits = b_chunk.trim_start_matches('0');
+          a_digits.len().cmp(&b_digits.len()).then_with(|| a_digits.cmp(b_digits))
+        } else {
+          a_chunk.cmp(b_chunk)
+        }
+      }
+    };
+    if ordering != Ordering::Equal {
+      return ordering;
+    }
+  }
+
+  fn is_digits(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+  }
+
+  /// Splits text into runs of ascii digits and runs of everything else.
+  struct Chunks<'a>(&'a str);
+
+  impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+      let first = self.0.chars().next()?;
+      let is_digit = first.is_ascii_digit();
+      let end = self
+        .0
+        .find(|c: char| c.is_ascii_digit() != is_digit)
+        .unwrap_or(self.0.len());
+      let (chunk, rest) = self.0.split_at(end);
+      self.0 = rest;
+      Some(chunk)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn natural_compare() {
+    let mut keys = vec!["item10", "item2", "item1", "item02", "b", "a10b", "a9c", "item"];
+    keys.sort_by(|a, b| compare_keys(a, b, SortKeysKind::Natural));
+    assert_eq!(
+      keys,
+      vec!["a9c", "a10b", "b", "item", "item1", "item02", "item2", "item10"]
+    );
+  }
+
+  #[test]
+  fn case_insensitive_compare() {
+    let mut keys = vec!["b", "B", "a", "C"];
+    keys.sort_by(|a, b| compare_keys(a, b, SortKeysKind::CaseInsensitive));
+    assert_eq!(keys, vec!["a", "B", "b", "C"]);
+  }
+}
@@ -0,0 +1,340 @@
+use std::borrow::Cow;
+
+use crate::configuration::Configuration;
+use crate::configuration::HexCaseKind;
+
+/// A piece of the text of a string literal found between its quotes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringPart<'a> {
+  /// A character that is not part of an escape sequence.
+  Char(char),
+  /// An escape sequence including its backslash (ex. `\n` or `\u00e9`).
+  Escape(&'a str),
+}
+
+impl StringPart<'_> {
+  /// Gets the character the part represents, not taking into account
+  /// that a `\u` escape may be half of a surrogate pair.
+  pub fn value(&self) -> Option<char> {
+    match self {
+      StringPart::Char(c) => Some(*c),
+      StringPart::Escape(text) => match &text[1..] {
+        "b" => Some('\u{08}'),
+        "f" => Some('\u{0C}'),
+        "n" => Some('\n'),
+        "r" => Some('\r'),
+        "t" => Some('\t'),
+        text if text.starts_with('u') => u32::from_str_radix(&text[1..], 16).ok().and_then(char::from_u32),
+        text => text.chars().next(),
+      },
+    }
+  }
+}
+
+/// Splits the text of a string literal (without the quotes) into characters and escape sequences.
+pub fn string_parts(text: &str) -> StringParts<'_> {
+  StringParts { text }
+}
+
+pub struct StringParts<'a> {
+  text: &'a str,
+}
+
+impl<'a> Iterator for StringParts<'a> {
+  type Item = StringPart<'a>;
+
+  fn next(&mut self) -> Option<StringPart<'a>> {
+    let mut chars = self.text.chars();
+    let c = chars.next()?;
+    if c != '\\' {
+      self.text = chars.as_str();
+      return Some(StringPart::Char(c));
+    }
+
+    let len = match chars.next() {
+      Some('u') if self.text.len() >= 6 && self.text.as_bytes()[2..6].iter().all(|b| b.is_ascii_hexdigit()) => 6,
+      Some(c) => 1 + c.len_utf8(),
+      None => 1,
+    };
+    let (escape, rest) = self.text.split_at(len);
+    self.text = rest;
+    Some(StringPart::Escape(escape))
+  }
+}
+
+/// Gets the number of double and single quote characters the string text represents.
+pub fn count_quotes(text: &str) -> (usize, usize) {
+  let mut double_count = 0;
+  let mut single_count = 0;
+  for part in string_parts(text) {
+    match part.value() {
+      Some('"') => double_count += 1,
+      Some('\'') => single_count += 1,
+      _ => {}
+    }
+  }
+  (double_count, single_count)
+}
+
+/// Formats the text of a string literal (without the quotes) so that it can be surrounded by
+/// the provided quote character, applying the `string.escapes.*` options.
+///
+/// The value the string represents is never changed.
+pub fn format_string<'a>(text: &'a str, quote: char, config: &Configuration) -> Cow<'a, str> {
+  if !has_escape_options(config) && !text.contains(['\\', quote]) {
+    return Cow::Borrowed(text);
+  }
+
+  let mut result = String::with_capacity(text.len());
+  let mut parts = string_parts(text).peekable();
+  while let Some(part) = parts.next() {
+    match part {
+      StringPart::Char(c) => push_char(&mut result, c, quote, config),
+      StringPart::Escape("\\\"") | StringPart::Escape("\\'") => {
+        push_char(&mut result, part.value().unwrap(), quote, config)
+      }
+      StringPart::Escape("\\/") if config.string_escapes_remove_unnecessary => result.push('/'),
+      StringPart::Escape(escape) if is_unicode_escape(escape) => {
+        let high = parse_unicode_escape(escape);
+        let low_escape = match parts.peek() {
+          Some(StringPart::Escape(next)) if is_unicode_escape(next) => Some(*next),
+          _ => None,
+        };
+        let pair = low_escape.and_then(|low_escape| {
+          let low = parse_unicode_escape(low_escape);
+          let is_pair = (0xD800..=0xDBFF).contains(&high) && (0xDC00..=0xDFFF).contains(&low);
+          is_pair.then(|| (low_escape, 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)))
+        });
+        match pair {
+          Some((low_escape, value)) => {
+            parts.next();
+            push_unicode_escape(&mut result, &[escape, low_escape], char::from_u32(value), quote, config);
+          }
+          None => push_unicode_escape(&mut result, &[escape], char::from_u32(high), quote, config),
+        }
+      }
+      StringPart::Escape(escape) => result.push_str(escape),
+    }
+  }
+
+  if result == text {
+    Cow::Borrowed(text)
+  } else {
+    Cow::Owned(result)
+  }
+}
+
+fn has_escape_options(config: &Configuration) -> bool {
+  config.string_escapes_remove_unnecessary
+    || config.string_escapes_unicode_to_character
+    || config.string_escapes_hex_case != HexCaseKind::Maintain
+    || config.string_escapes_prefer_short
+    || config.ensure_ascii
+    || config.html_safe
+}
+
+/// Gets if the character must be written as a unicode escape based on the configuration.
+fn must_escape_char(c: char, config: &Configuration) -> bool {
+  (config.ensure_ascii && !c.is_ascii()) || (config.html_safe && matches!(c, '<' | '>' | '&' | '\u{2028}' | '\u{2029}'))
+}
+
+fn push_char(result: &mut String, c: char, quote: char, config: &Configuration) {
+  if must_escape_char(c, config) {
+    let mut buffer = [0; 2];
+    for unit in c.encode_utf16(&mut buffer) {
+      push_hex_escape(result, *unit, config);
+    }
+    return;
+  }
+  if c == quote || c == '\\' {
+    result.push('\\');
+  }
+  result.push(c);
+}
+
+fn push_hex_escape(result: &mut String, unit: u16, config: &Configuration) {
+  match config.string_escapes_hex_case {
+    HexCaseKind::Maintain | HexCaseKind::Lowercase => result.push_str(&format!("\\u{unit:04x}")),
+    HexCaseKind::Uppercase => result.push_str(&format!("\\u{unit:04X}")),
+  }
+}
+
+fn push_unicode_escape(
+  result: &mut String,
+  escapes: &[&str],
+  value: Option<char>,
+  quote: char,
+  config: &Configuration,
+) {
+  if let Some(c) = value {
+    if let Some(short_escape) = get_short_escape(c, quote).filter(|_| config.string_escapes_prefer_short) {
+      result.push_str(short_escape);
+      return;
+    }
+    // converting to the character would only escape it again
+    if config.string_escapes_unicode_to_character && is_printable(c) && !must_escape_char(c, config) {
+      push_char(result, c, quote, config);
+      return;
+    }
+  }
+
+  for escape in escapes {
+    match config.string_escapes_hex_case {
+      HexCaseKind::Maintain => result.push_str(escape),
+      HexCaseKind::Lowercase => {
+        result.push_str("\\u");
+        result.push_str(&escape[2..].to_ascii_lowercase());
+      }
+      HexCaseKind::Uppercase => {
+        result.push_str("\\u");
+        result.push_str(&escape[2..].to_ascii_uppercase());
+      }
+    }
+  }
+}
+
+fn get_short_escape(c: char, quote: char) -> Option<&'static str> {
+  match c {
+    '\u{08}' => Some("\\b"),
+    '\u{0C}' => Some("\\f"),
+    '\n' => Some("\\n"),
+    '\r' => Some("\\r"),
+    '\t' => Some("\\t"),
+    '\\' => Some("\\\\"),
+    '"' if quote == '"' => Some("\\\""),
+    '\'' if quote == '\'' => Some("\\'"),
+    _ => None,
+  }
+}
+
+/// Gets if the character is visible when written literally, so replacing its
+/// escape won't hide it from someone reading the file.
+fn is_printable(c: char) -> bool {
+  if c.is_control() || (c.is_whitespace() && c != ' ') {
+    return false;
+  }
+  !matches!(
+    c,
+    '\u{AD}'
+      | '\u{34F}'
+      | '\u{61C}'
+      | '\u{180E}'
+      | '\u{200B}'..='\u{200F}'
+      | '\u{202A}'..='\u{202E}'
+      | '\u{2060}'..='\u{206F}'
+      | '\u{FE00}'..='\u{FE0F}'
+      | '\u{FEFF}'
+      | '\u{FFF9}'..='\u{FFFB}'
+  )
+}
+
+fn is_unicode_escape(escape: &str) -> bool {
+  escape.len() == 6 && escape.starts_with("\\u")
+}
+
+fn parse_unicode_escape(escape: &str) -> u32 {
+  u32::from_str_radix(&escape[2..], 16).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::configuration::ConfigurationBuilder;
+
+  use super::*;
+
+  #[test]
+  fn splits_escapes() {
+    assert_eq!(
+      string_parts(r#"a\\'\u00e9\n\u12"#).collect::<Vec<_>>(),
+      vec![
+        StringPart::Char('a'),
+        StringPart::Escape(r"\\"),
+        StringPart::Char('\''),
+        StringPart::Escape(r"\u00e9"),
+        StringPart::Escape(r"\n"),
+        StringPart::Escape(r"\u"),
+        StringPart::Char('1'),
+        StringPart::Char('2'),
+      ]
+    );
+  }
+
+  #[test]
+  fn requotes() {
+    let config = ConfigurationBuilder::new().build();
+    assert_eq!(format_string(r#"it\'s "quoted""#, '"', &config), r#"it's \"quoted\""#);
+    assert_eq!(format_string(r#"it's \"quoted\""#, '\'', &config), r#"it\'s "quoted""#);
+    assert_eq!(format_string(r"a\\'b\\\'c", '"', &config), r"a\\'b\\'c");
+    assert_eq!(format_string(r#"a\\"b"#, '\'', &config), r#"a\\"b"#);
+    assert_eq!(format_string(r"\/\u00e9", '"', &config), r"\/\u00e9");
+  }
+
+  #[test]
+  fn formats_escapes() {
+    let config = ConfigurationBuilder::new()
+      .string_escapes_remove_unnecessary(true)
+      .string_escapes_unicode_to_character(true)
+      .string_escapes_hex_case(HexCaseKind::Uppercase)
+      .string_escapes_prefer_short(true)
+      .build();
+    assert_eq!(format_string(r"a\/b", '"', &config), "a/b");
+    assert_eq!(format_string(r"\u00e9\u0041", '"', &config), "\u{e9}A");
+    assert_eq!(format_string(r"\ud83d\ude00", '"', &config), "\u{1F600}");
+    assert_eq!(format_string(r"\u000a\u0009\u005c", '"', &config), r"\n\t\\");
+    assert_eq!(format_string(r"\u0022\u0027", '"', &config), r#"\"'"#);
+    assert_eq!(format_string(r"\u0022\u0027", '\'', &config), r#""\'"#);
+    assert_eq!(
+      format_string(r"\u0001\u200b\u2028", '"', &config),
+      r"\u0001\u200B\u2028"
+    );
+    // lone surrogates stay escaped
+    assert_eq!(format_string(r"\ud83d\u0041", '"', &config), r"\uD83DA");
+    assert_eq!(format_string(r"\ude00", '"', &config), r"\uDE00");
+  }
+
+  #[test]
+  fn ensures_ascii() {
+    let config = ConfigurationBuilder::new().ensure_ascii(true).build();
+    assert_eq!(
+      format_string("caf\u{e9} \u{1F600}", '"', &config),
+      r"caf\u00e9 \ud83d\ude00"
+    );
+    assert_eq!(format_string(r"\u00E9", '"', &config), r"\u00E9");
+    let config = ConfigurationBuilder::new()
+      .ensure_ascii(true)
+      .string_escapes_unicode_to_character(true)
+      .string_escapes_hex_case(HexCaseKind::Uppercase)
+      .build();
+    assert_eq!(format_string("\u{e9}\\u0041\\u00e9", '"', &config), r"\u00E9A\u00E9");
+  }
+
+  #[test]
+  fn escapes_html() {
+    let config = ConfigurationBuilder::new().html_safe(true).build();
+    assert_eq!(
+      format_string("</script> & \u{2028}\u{2029}", '"', &config),
+      r"\u003c/script\u003e \u0026 \u2028\u2029"
+    );
+    let config = ConfigurationBuilder::new()
+      .html_safe(true)
+      .string_escapes_unicode_to_character(true)
+      .build();
+    assert_eq!(format_string(r"\u003C\u0041", '"', &config), r"\u003CA");
+  }
+
+  #[test]
+  fn formats_hex_case() {
+    let config = ConfigurationBuilder::new()
+      .string_escapes_hex_case(HexCaseKind::Lowercase)
+      .build();
+    assert_eq!(
+      format_string(r"\u00E9\uD83D\uDE00\N", '"', &config),
+      r"\u00e9\ud83d\ude00\N"
+    );
+  }
+
+  #[test]
+  fn counts_quotes() {
+    assert_eq!(count_quotes(r#"\"a' \\" '"#), (2, 2));
+  }
+}
@@ -2,6 +2,10 @@ pub mod configuration;
 mod format_text;
 mod generation;
 
+pub use format_text::DuplicateKey;
+pub use format_text::FormatRangeResult;
+pub use format_text::find_duplicate_keys;
+pub use format_text::format_range;
 pub use format_text::format_text;
 
 #[cfg(feature = "tracing")]
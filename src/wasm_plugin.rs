@@ -28,7 +28,7 @@ impl SyncPluginHandler<Configuration> for JsonPluginHandler {
       config: config.config,
       diagnostics: config.diagnostics,
       file_matching: FileMatchingInfo {
-        file_extensions: vec!["json".to_string(), "jsonc".to_string()],
+        file_extensions: vec!["json".to_string(), "jsonc".to_string(), "json5".to_string()],
         file_names: vec![],
       },
     }
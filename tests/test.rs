@@ -13,6 +13,8 @@ use dprint_plugin_json::configuration::resolve_config;
 use dprint_plugin_json::*;
 
 #[test]
+// clippy 1.95 flags the existing borrows of the spec path and text passed to `format_text`
+#[allow(clippy::needless_borrow)]
 fn test_specs() {
   //debug_here!();
   let global_config = GlobalConfiguration::default();
@@ -50,3 +52,31 @@ fn test_specs() {
     }),
   )
 }
+
+/// Runs the specs whose expected output is the error formatting the file text fails with.
+#[test]
+fn test_error_specs() {
+  let global_config = GlobalConfiguration::default();
+
+  run_specs(
+    &PathBuf::from("./tests/error_specs"),
+    &ParseSpecOptions {
+      default_file_name: "file.json",
+    },
+    &RunSpecsOptions {
+      fix_failures: false,
+      format_twice: false,
+    },
+    Arc::new(move |path, file_text, spec_config| {
+      let spec_config: ConfigKeyMap = serde_json::from_value(spec_config.clone().into()).unwrap();
+      let config_result = resolve_config(spec_config, &global_config);
+      ensure_no_diagnostics(&config_result.diagnostics);
+
+      match format_text(path, file_text, &config_result.config) {
+        Ok(result) => Ok(result),
+        Err(err) => Ok(Some(format!("{}\n", err))),
+      }
+    }),
+    Arc::new(move |_, _, _| panic!("Tracing is not supported for error specs")),
+  )
+}